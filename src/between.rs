@@ -0,0 +1,155 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::{dates_in_range, stepped_weekdays_in_range, Recurrence};
+
+/// An inclusive range of dates, for listing the dates in it that match a rule.
+///
+/// Both `start` and `end` belong to the range, and every method returns its dates in ascending
+/// order. A range whose `end` is before its `start` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::{Between, Recurrence};
+///
+/// let october = Between {
+///     start: NaiveDate::from_ymd_opt(2023, 10, 1).unwrap(),
+///     end: NaiveDate::from_ymd_opt(2023, 10, 31).unwrap(),
+/// };
+/// assert_eq!(october.weekdays(Weekday::Tue).len(), 5);
+/// assert_eq!(october.days_of_month(31).len(), 1);
+/// assert_eq!(october.occurrences_of(&Recurrence::Weekly(Weekday::Fri)).len(), 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Between {
+    /// The first date of the range.
+    pub start: NaiveDate,
+    /// The last date of the range.
+    pub end: NaiveDate,
+}
+
+impl Between {
+    /// Lists the dates in the range that fall on `weekday`.
+    pub fn weekdays(&self, weekday: Weekday) -> Vec<NaiveDate> {
+        stepped_weekdays_in_range(&self.start, &self.end, weekday, 1)
+    }
+
+    /// Lists the dates in the range whose day of the month is `day`.
+    ///
+    /// Months without that day are skipped, and the result is empty if `day` is invalid.
+    pub fn days_of_month(&self, day: u32) -> Vec<NaiveDate> {
+        dates_in_range(&self.start, &self.end)
+            .filter(|date| date.day() == day)
+            .collect()
+    }
+
+    /// Lists the dates in the range that are occurrences of `recurrence`.
+    pub fn occurrences_of(&self, recurrence: &Recurrence) -> Vec<NaiveDate> {
+        if self.end < self.start {
+            return Vec::new();
+        }
+
+        let first = recurrence.matches(&self.start).then_some(self.start);
+        first
+            .into_iter()
+            .chain(recurrence.occurrences(&self.start))
+            .take_while(|date| *date <= self.end)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn october() -> Between {
+        Between {
+            start: NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(), // Monday
+            end: NaiveDate::from_ymd_opt(2023, 10, 31).unwrap(),
+        }
+    }
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2023, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_between_weekdays() {
+        // Test case 1: The start date is included
+        assert_eq!(
+            october().weekdays(Weekday::Mon),
+            [
+                date(10, 2),
+                date(10, 9),
+                date(10, 16),
+                date(10, 23),
+                date(10, 30)
+            ]
+        );
+
+        // Test case 2: The end date is included
+        assert_eq!(
+            october().weekdays(Weekday::Tue),
+            [
+                date(10, 3),
+                date(10, 10),
+                date(10, 17),
+                date(10, 24),
+                date(10, 31)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_between_days_of_month() {
+        // Test case 1: Days at both ends and inside the range
+        assert_eq!(october().days_of_month(2), [date(10, 2)]);
+        assert_eq!(october().days_of_month(31), [date(10, 31)]);
+        assert!(october().days_of_month(1).is_empty());
+
+        // Test case 2: Months without the day are skipped
+        let range = Between {
+            start: date(1, 1),
+            end: date(6, 30),
+        };
+        assert_eq!(
+            range.days_of_month(31),
+            [date(1, 31), date(3, 31), date(5, 31)]
+        );
+
+        // Test case 3: An invalid day
+        assert!(october().days_of_month(32).is_empty());
+    }
+
+    #[test]
+    fn test_between_occurrences_of() {
+        // Test case 1: The same range as the other methods gives the same dates
+        let range = october();
+        assert_eq!(
+            range.occurrences_of(&Recurrence::Weekly(Weekday::Mon)),
+            range.weekdays(Weekday::Mon)
+        );
+        assert_eq!(
+            range.occurrences_of(&Recurrence::DayOfMonth(31)),
+            range.days_of_month(31)
+        );
+
+        // Test case 2: An annual date inside the range, and one outside
+        let halloween = Recurrence::AnnualDate { month: 10, day: 31 };
+        assert_eq!(range.occurrences_of(&halloween), [date(10, 31)]);
+        let christmas = Recurrence::AnnualDate { month: 12, day: 25 };
+        assert!(range.occurrences_of(&christmas).is_empty());
+
+        // Test case 3: A reversed range is empty
+        let reversed = Between {
+            start: range.end,
+            end: range.start,
+        };
+        assert!(reversed
+            .occurrences_of(&Recurrence::Weekly(Weekday::Mon))
+            .is_empty());
+        assert!(reversed.weekdays(Weekday::Mon).is_empty());
+        assert!(reversed.days_of_month(15).is_empty());
+    }
+}
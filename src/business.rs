@@ -0,0 +1,502 @@
+use std::collections::{BTreeSet, HashSet};
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+/// A source of holidays used by business-day calculations.
+///
+/// Business days are Monday through Friday, excluding the dates for which
+/// [`is_holiday`](HolidayCalendar::is_holiday) returns `true`. The trait is implemented for
+/// [`NoHolidays`] and for common collections of dates, so a fixed list of holidays can be passed
+/// directly.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::HolidayCalendar;
+///
+/// let holidays = vec![NaiveDate::from_ymd_opt(2023, 12, 25).unwrap()];
+/// assert!(holidays.is_holiday(&NaiveDate::from_ymd_opt(2023, 12, 25).unwrap()));
+/// ```
+pub trait HolidayCalendar {
+    /// Returns `true` if `date` is a holiday.
+    fn is_holiday(&self, date: &NaiveDate) -> bool;
+}
+
+/// A [`HolidayCalendar`] without any holidays, so only weekends are skipped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NoHolidays;
+
+impl HolidayCalendar for NoHolidays {
+    fn is_holiday(&self, _date: &NaiveDate) -> bool {
+        false
+    }
+}
+
+impl<T: HolidayCalendar + ?Sized> HolidayCalendar for &T {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        (**self).is_holiday(date)
+    }
+}
+
+impl HolidayCalendar for [NaiveDate] {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        self.contains(date)
+    }
+}
+
+impl<const N: usize> HolidayCalendar for [NaiveDate; N] {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        self.contains(date)
+    }
+}
+
+impl HolidayCalendar for Vec<NaiveDate> {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        self.contains(date)
+    }
+}
+
+impl HolidayCalendar for HashSet<NaiveDate> {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        self.contains(date)
+    }
+}
+
+impl HolidayCalendar for BTreeSet<NaiveDate> {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        self.contains(date)
+    }
+}
+
+/// A [`HolidayCalendar`] that combines several calendars, such as national and company
+/// holidays.
+///
+/// A date is a holiday if any member calendar reports it as one, so the composite is the union
+/// of its members. A composite without members has no holidays.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::{CompositeCalendar, HolidayCalendar};
+///
+/// let national = vec![NaiveDate::from_ymd_opt(2023, 12, 25).unwrap()];
+/// let company = [NaiveDate::from_ymd_opt(2023, 12, 27).unwrap()];
+/// let calendar = CompositeCalendar(vec![Box::new(national), Box::new(company)]);
+/// assert!(calendar.is_holiday(&NaiveDate::from_ymd_opt(2023, 12, 25).unwrap()));
+/// assert!(calendar.is_holiday(&NaiveDate::from_ymd_opt(2023, 12, 27).unwrap()));
+/// assert!(!calendar.is_holiday(&NaiveDate::from_ymd_opt(2023, 12, 26).unwrap()));
+/// ```
+#[derive(Default)]
+pub struct CompositeCalendar(pub Vec<Box<dyn HolidayCalendar>>);
+
+impl HolidayCalendar for CompositeCalendar {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        self.0.iter().any(|calendar| calendar.is_holiday(date))
+    }
+}
+
+/// Returns `true` if `date` is a Monday through Friday that is not a holiday in `calendar`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::{is_business_day, NoHolidays};
+///
+/// let saturday = NaiveDate::from_ymd_opt(2023, 9, 30).unwrap();
+/// assert!(!is_business_day(&saturday, &NoHolidays));
+/// ```
+pub fn is_business_day(date: &NaiveDate, calendar: &impl HolidayCalendar) -> bool {
+    !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !calendar.is_holiday(date)
+}
+
+/// Returns the date `n` business days after `date`.
+///
+/// Business days are counted strictly after `date`, so `date` itself never counts even if it is
+/// a business day, and `n = 0` returns `date` unchanged.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the nth business day after `date`. Returns `None` if the
+/// calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::{add_business_days, NoHolidays};
+///
+/// let friday = NaiveDate::from_ymd_opt(2023, 9, 29).unwrap();
+/// assert_eq!(
+///     add_business_days(&friday, 2, &NoHolidays),
+///     NaiveDate::from_ymd_opt(2023, 10, 3)
+/// );
+/// ```
+pub fn add_business_days(
+    date: &NaiveDate,
+    n: u32,
+    calendar: &impl HolidayCalendar,
+) -> Option<NaiveDate> {
+    let mut date = *date;
+    let mut remaining = n;
+    while remaining > 0 {
+        date = date.succ_opt()?;
+        if is_business_day(&date, calendar) {
+            remaining -= 1;
+        }
+    }
+    Some(date)
+}
+
+/// Returns the date `n` business days before `date`.
+///
+/// This mirrors [`add_business_days`] going backward: business days are counted strictly
+/// before `date`, so `date` itself never counts even if it is a business day, and `n = 0`
+/// returns `date` unchanged.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the nth business day before `date`. Returns `None` if the
+/// calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::{subtract_business_days, NoHolidays};
+///
+/// let tuesday = NaiveDate::from_ymd_opt(2023, 10, 3).unwrap();
+/// assert_eq!(
+///     subtract_business_days(&tuesday, 2, &NoHolidays),
+///     NaiveDate::from_ymd_opt(2023, 9, 29)
+/// );
+/// ```
+pub fn subtract_business_days(
+    date: &NaiveDate,
+    n: u32,
+    calendar: &impl HolidayCalendar,
+) -> Option<NaiveDate> {
+    let mut date = *date;
+    let mut remaining = n;
+    while remaining > 0 {
+        date = date.pred_opt()?;
+        if is_business_day(&date, calendar) {
+            remaining -= 1;
+        }
+    }
+    Some(date)
+}
+
+/// Counts the business days in the range `(start, end]`.
+///
+/// `start` itself is excluded and `end` is included, so ordering on a Friday and shipping on
+/// the following Monday counts one business day, matching [`add_business_days`]. The weekdays
+/// are counted arithmetically, but `calendar` can only be asked about one date at a time, so it
+/// is still consulted once for every weekday in the range, jumping over the weekends.
+///
+/// # Returns
+///
+/// The number of business days. Returns 0 if `end` is not after `start`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::{count_business_days, NoHolidays};
+///
+/// let ordered = NaiveDate::from_ymd_opt(2023, 9, 29).unwrap(); // Friday
+/// let shipped = NaiveDate::from_ymd_opt(2023, 10, 3).unwrap(); // Tuesday
+/// assert_eq!(count_business_days(&ordered, &shipped, &NoHolidays), 2);
+/// ```
+pub fn count_business_days(
+    start: &NaiveDate,
+    end: &NaiveDate,
+    calendar: &impl HolidayCalendar,
+) -> u64 {
+    if end <= start {
+        return 0;
+    }
+
+    let days = (*end - *start).num_days() as u64;
+    let first = u64::from(start.weekday().num_days_from_monday()) + 1;
+    let partial = (first..first + days % 7)
+        .filter(|offset| offset % 7 < 5)
+        .count() as u64;
+    let weekdays = days / 7 * 5 + partial;
+
+    let next_weekday = |date: &NaiveDate| {
+        let days = match date.weekday() {
+            Weekday::Fri => 3,
+            Weekday::Sat => 2,
+            _ => 1,
+        };
+        date.checked_add_days(Days::new(days))
+    };
+    let holidays = std::iter::successors(next_weekday(start), next_weekday)
+        .take_while(|date| date <= end)
+        .filter(|date| calendar.is_holiday(date))
+        .count() as u64;
+    weekdays - holidays
+}
+
+/// How a date that falls on a weekend is moved onto a weekday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WeekendRule {
+    /// Move a weekend date back to the preceding Friday.
+    Preceding,
+    /// Move a weekend date forward to the following Monday.
+    Following,
+}
+
+impl WeekendRule {
+    /// Applies the rule to `date`, returning it unchanged if it is already a weekday.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<NaiveDate>` containing the adjusted date. Returns `None` if the calculation
+    /// overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use next_matching_day::WeekendRule;
+    ///
+    /// let sunday = NaiveDate::from_ymd_opt(2023, 10, 1).unwrap();
+    /// assert_eq!(
+    ///     WeekendRule::Preceding.adjust(&sunday),
+    ///     NaiveDate::from_ymd_opt(2023, 9, 29)
+    /// );
+    /// assert_eq!(
+    ///     WeekendRule::Following.adjust(&sunday),
+    ///     NaiveDate::from_ymd_opt(2023, 10, 2)
+    /// );
+    /// ```
+    pub fn adjust(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        match (self, date.weekday()) {
+            (WeekendRule::Preceding, Weekday::Sat) => date.checked_sub_days(Days::new(1)),
+            (WeekendRule::Preceding, Weekday::Sun) => date.checked_sub_days(Days::new(2)),
+            (WeekendRule::Following, Weekday::Sat) => date.checked_add_days(Days::new(2)),
+            (WeekendRule::Following, Weekday::Sun) => date.checked_add_days(Days::new(1)),
+            _ => Some(*date),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[test]
+    fn test_holiday_calendar_impls() {
+        let christmas = NaiveDate::from_ymd_opt(2023, 12, 25).unwrap();
+        let boxing_day = NaiveDate::from_ymd_opt(2023, 12, 26).unwrap();
+
+        // Test case 1: No holidays
+        assert!(!NoHolidays.is_holiday(&christmas));
+
+        // Test case 2: Collections of dates
+        assert!([christmas].is_holiday(&christmas));
+        assert!(![christmas].is_holiday(&boxing_day));
+        assert!(vec![christmas].is_holiday(&christmas));
+        assert!(HashSet::from([christmas]).is_holiday(&christmas));
+        assert!(BTreeSet::from([christmas]).is_holiday(&christmas));
+        assert!([christmas][..].is_holiday(&christmas));
+
+        // Test case 3: References delegate to the referenced calendar
+        let holidays = vec![christmas];
+        assert!(is_business_day(&boxing_day, &&holidays));
+        assert!(!is_business_day(&christmas, &&holidays));
+    }
+
+    #[test]
+    fn test_is_business_day() {
+        let holidays = [NaiveDate::from_ymd_opt(2023, 12, 25).unwrap()];
+
+        // Test case 1: A weekday
+        let date = NaiveDate::from_ymd_opt(2023, 12, 22).unwrap(); // Friday
+        assert!(is_business_day(&date, &holidays));
+
+        // Test case 2: Weekends
+        let date = NaiveDate::from_ymd_opt(2023, 12, 23).unwrap(); // Saturday
+        assert!(!is_business_day(&date, &NoHolidays));
+        let date = NaiveDate::from_ymd_opt(2023, 12, 24).unwrap(); // Sunday
+        assert!(!is_business_day(&date, &NoHolidays));
+
+        // Test case 3: A holiday on a weekday
+        let date = NaiveDate::from_ymd_opt(2023, 12, 25).unwrap(); // Monday
+        assert!(!is_business_day(&date, &holidays));
+        assert!(is_business_day(&date, &NoHolidays));
+    }
+
+    #[test]
+    fn test_add_business_days() {
+        let friday = NaiveDate::from_ymd_opt(2023, 12, 22).unwrap();
+
+        // Test case 1: Zero days is the date itself
+        assert_eq!(add_business_days(&friday, 0, &NoHolidays), Some(friday));
+
+        // Test case 2: Crossing a weekend
+        let result = add_business_days(&friday, 1, &NoHolidays);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 12, 25));
+
+        // Test case 3: Crossing a weekend and holidays
+        let holidays = [
+            NaiveDate::from_ymd_opt(2023, 12, 25).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 12, 26).unwrap(),
+        ];
+        let result = add_business_days(&friday, 2, &holidays);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 12, 28));
+
+        // Test case 4: Starting on a weekend
+        let saturday = NaiveDate::from_ymd_opt(2023, 12, 23).unwrap();
+        let result = add_business_days(&saturday, 1, &NoHolidays);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 12, 25));
+    }
+
+    #[test]
+    fn test_subtract_business_days() {
+        let monday = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+
+        // Test case 1: Zero days is the date itself
+        assert_eq!(
+            subtract_business_days(&monday, 0, &NoHolidays),
+            Some(monday)
+        );
+
+        // Test case 2: Crossing a weekend backward
+        let result = subtract_business_days(&monday, 1, &NoHolidays);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 12, 29));
+
+        // Test case 3: Crossing a weekend and a holiday backward
+        let holidays = [NaiveDate::from_ymd_opt(2023, 12, 29).unwrap()];
+        let result = subtract_business_days(&monday, 2, &holidays);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 12, 27));
+
+        // Test case 4: Starting on a weekend
+        let sunday = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+        let result = subtract_business_days(&sunday, 1, &NoHolidays);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 12, 29));
+
+        // Test case 5: Undoes add_business_days from a business day
+        let holidays = [
+            NaiveDate::from_ymd_opt(2023, 12, 25).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 12, 26).unwrap(),
+        ];
+        let friday = NaiveDate::from_ymd_opt(2023, 12, 22).unwrap();
+        for n in 0..10 {
+            let later = add_business_days(&friday, n, &holidays).unwrap();
+            assert_eq!(subtract_business_days(&later, n, &holidays), Some(friday));
+        }
+    }
+
+    #[test]
+    fn test_weekend_rule_adjust() {
+        let saturday = NaiveDate::from_ymd_opt(2023, 9, 30).unwrap();
+        let sunday = NaiveDate::from_ymd_opt(2023, 10, 1).unwrap();
+        let monday = NaiveDate::from_ymd_opt(2023, 10, 2).unwrap();
+        let friday = NaiveDate::from_ymd_opt(2023, 9, 29).unwrap();
+
+        // Test case 1: Preceding
+        assert_eq!(WeekendRule::Preceding.adjust(&saturday), Some(friday));
+        assert_eq!(WeekendRule::Preceding.adjust(&sunday), Some(friday));
+
+        // Test case 2: Following
+        assert_eq!(WeekendRule::Following.adjust(&saturday), Some(monday));
+        assert_eq!(WeekendRule::Following.adjust(&sunday), Some(monday));
+
+        // Test case 3: Weekdays are unchanged
+        assert_eq!(WeekendRule::Preceding.adjust(&monday), Some(monday));
+        assert_eq!(WeekendRule::Following.adjust(&friday), Some(friday));
+    }
+
+    #[test]
+    fn test_composite_calendar() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+        let national = vec![date(12, 25), date(12, 26)];
+        let company = HashSet::from([date(12, 26), date(12, 27)]);
+        let calendar = CompositeCalendar(vec![Box::new(national), Box::new(company)]);
+
+        // Test case 1: A holiday in either member is a holiday in the composite
+        for day in [25, 26, 27] {
+            assert!(calendar.is_holiday(&date(12, day)));
+        }
+        assert!(!calendar.is_holiday(&date(12, 28)));
+
+        // Test case 2: Business-day math skips the union of both calendars
+        let friday = date(12, 22);
+        assert_eq!(add_business_days(&friday, 1, &calendar), Some(date(12, 28)));
+
+        // Test case 3: An empty composite has no holidays
+        assert!(!CompositeCalendar::default().is_holiday(&date(12, 25)));
+    }
+
+    #[test]
+    fn test_count_business_days() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+
+        // Test case 1: Multiple weeks without holidays, agreeing with a day-by-day count
+        let start = date(10, 4); // Wednesday
+        for end_day in 4..=31 {
+            let end = date(10, end_day);
+            let expected = start
+                .iter_days()
+                .skip(1)
+                .take_while(|day| *day <= end)
+                .filter(|day| is_business_day(day, &NoHolidays))
+                .count() as u64;
+            assert_eq!(count_business_days(&start, &end, &NoHolidays), expected);
+        }
+        assert_eq!(
+            count_business_days(&date(10, 6), &date(10, 27), &NoHolidays),
+            15
+        );
+
+        // Test case 2: An interior holiday and a holiday on a weekend
+        let holidays = [date(10, 9), date(10, 14)]; // Monday and Saturday
+        assert_eq!(
+            count_business_days(&date(10, 6), &date(10, 27), &holidays),
+            14
+        );
+
+        // Test case 3: The range is exclusive of start and inclusive of end
+        assert_eq!(
+            count_business_days(&date(10, 6), &date(10, 9), &NoHolidays),
+            1
+        );
+        assert_eq!(
+            count_business_days(&date(10, 6), &date(10, 9), &holidays),
+            0
+        );
+
+        // Test case 4: An empty or reversed range
+        assert_eq!(
+            count_business_days(&date(10, 6), &date(10, 6), &NoHolidays),
+            0
+        );
+        assert_eq!(
+            count_business_days(&date(10, 27), &date(10, 6), &NoHolidays),
+            0
+        );
+
+        // Test case 5: The calendar is only asked about the weekdays in the range
+        struct Recording(RefCell<Vec<NaiveDate>>);
+        impl HolidayCalendar for Recording {
+            fn is_holiday(&self, date: &NaiveDate) -> bool {
+                self.0.borrow_mut().push(*date);
+                false
+            }
+        }
+        for start_day in 6..=9 {
+            let calendar = Recording(RefCell::new(Vec::new()));
+            let count = count_business_days(&date(10, start_day), &date(10, 22), &calendar);
+            let asked = calendar.0.into_inner();
+            assert_eq!(asked.len() as u64, count);
+            assert!(asked.iter().all(|day| is_business_day(day, &NoHolidays)));
+            assert!(asked.iter().all(|day| *day > date(10, start_day)));
+        }
+    }
+}
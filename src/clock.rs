@@ -0,0 +1,186 @@
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
+
+use crate::Recurrence;
+
+/// Returns the current date in UTC.
+///
+/// The `today_*` functions are the only ones in this crate that read the system clock. Every
+/// other function takes its start date as an argument, so the current date is always an
+/// explicit input rather than hidden state.
+///
+/// Use this when schedules are defined in UTC. Near midnight the UTC date can differ from the
+/// local date returned by [`today_local`], which is a common source of off-by-one-day surprises
+/// when the intent is not explicit at the call site.
+///
+/// This function is only available with the `clock` feature, which is enabled by default.
+///
+/// # Examples
+///
+/// ```
+/// use next_matching_day::{find_next_day_of_month, today_utc};
+///
+/// let next_first = find_next_day_of_month(&today_utc(), 1).unwrap();
+/// assert!(next_first > today_utc());
+/// ```
+pub fn today_utc() -> NaiveDate {
+    today_in(&Utc)
+}
+
+/// Returns the current date in the system's local time zone.
+///
+/// Use this when schedules follow the wall clock of the machine running them. See
+/// [`today_utc`] for the UTC equivalent.
+///
+/// This function is only available with the `clock` feature, which is enabled by default.
+pub fn today_local() -> NaiveDate {
+    today_in(&Local)
+}
+
+/// Returns the current date in the given time zone.
+///
+/// This function is only available with the `clock` feature, which is enabled by default.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::FixedOffset;
+/// use next_matching_day::today_in;
+///
+/// let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+/// let today = today_in(&tokyo);
+/// ```
+pub fn today_in<Tz: TimeZone>(tz: &Tz) -> NaiveDate {
+    date_at(&Utc::now(), tz)
+}
+
+impl Recurrence {
+    /// Returns `true` if today's local date is an occurrence of the recurrence.
+    ///
+    /// This is [`Recurrence::matches`] applied to [`today_local`], the single check a daily
+    /// job needs. Use `matches` with [`today_in`] when the schedule follows another time zone.
+    ///
+    /// This method is only available with the `clock` feature, which is enabled by default.
+    pub fn is_due_today(&self) -> bool {
+        self.matches(&today_local())
+    }
+}
+
+/// Returns the date that the instant `now` falls on in the given time zone.
+fn date_at<Tz: TimeZone>(now: &DateTime<Utc>, tz: &Tz) -> NaiveDate {
+    now.with_timezone(tz).date_naive()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+
+    #[test]
+    fn test_today_utc_and_local() {
+        // Both return valid dates that are at most a day apart, whatever the local time zone.
+        let utc = today_utc();
+        let local = today_local();
+        assert!((utc - local).num_days().abs() <= 1);
+    }
+
+    #[test]
+    fn test_recurrence_is_due_today() {
+        use chrono::Datelike;
+
+        // Retry if the test happens to run across midnight.
+        let (today, due) = loop {
+            let today = today_local();
+            let recurrences = [
+                Recurrence::Weekly(today.weekday()),
+                Recurrence::Weekly(today.weekday().succ()),
+                Recurrence::DayOfMonth(today.day()),
+                Recurrence::AnnualDate {
+                    month: today.month(),
+                    day: today.day(),
+                },
+            ];
+            let due = recurrences.map(|recurrence| {
+                let is_due = recurrence.is_due_today();
+                (recurrence, is_due)
+            });
+            if today_local() == today {
+                break (today, due);
+            }
+        };
+
+        for (recurrence, is_due) in &due {
+            assert_eq!(*is_due, recurrence.matches(&today));
+        }
+        assert_eq!(due.map(|(_, is_due)| is_due), [true, false, true, true]);
+    }
+
+    #[test]
+    fn test_date_at_mocked_offset() {
+        let east = FixedOffset::east_opt(9 * 3600).unwrap();
+        let west = FixedOffset::west_opt(5 * 3600).unwrap();
+
+        // Test case 1: Shortly after UTC midnight, a western offset is still on the previous day
+        let now = Utc.with_ymd_and_hms(2023, 10, 16, 2, 0, 0).unwrap();
+        assert_eq!(
+            date_at(&now, &Utc),
+            NaiveDate::from_ymd_opt(2023, 10, 16).unwrap()
+        );
+        assert_eq!(
+            date_at(&now, &west),
+            NaiveDate::from_ymd_opt(2023, 10, 15).unwrap()
+        );
+        assert_eq!(
+            date_at(&now, &east),
+            NaiveDate::from_ymd_opt(2023, 10, 16).unwrap()
+        );
+
+        // Test case 2: Late in the UTC day, an eastern offset is already on the next day
+        let now = Utc.with_ymd_and_hms(2023, 10, 16, 20, 0, 0).unwrap();
+        assert_eq!(
+            date_at(&now, &Utc),
+            NaiveDate::from_ymd_opt(2023, 10, 16).unwrap()
+        );
+        assert_eq!(
+            date_at(&now, &west),
+            NaiveDate::from_ymd_opt(2023, 10, 16).unwrap()
+        );
+        assert_eq!(
+            date_at(&now, &east),
+            NaiveDate::from_ymd_opt(2023, 10, 17).unwrap()
+        );
+
+        // Test case 3: At midday both offsets agree
+        let now = Utc.with_ymd_and_hms(2023, 10, 16, 12, 0, 0).unwrap();
+        assert_eq!(date_at(&now, &west), date_at(&now, &east));
+    }
+
+    #[test]
+    fn test_finders_only_depend_on_the_injected_date() {
+        use crate::{find_next_day_of_month, find_next_weekday};
+        use chrono::Weekday;
+
+        // The same instant falls on different dates in different time zones, but once the date
+        // is resolved the results depend on nothing else.
+        let now = Utc.with_ymd_and_hms(2023, 10, 31, 23, 0, 0).unwrap();
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+        let cases = [
+            (
+                FixedOffset::west_opt(10 * 3600).unwrap(),
+                date(10, 31),
+                date(11, 1),
+            ),
+            (FixedOffset::east_opt(0).unwrap(), date(10, 31), date(11, 1)),
+            (
+                FixedOffset::east_opt(14 * 3600).unwrap(),
+                date(11, 1),
+                date(12, 1),
+            ),
+        ];
+
+        for (offset, today, next_first) in cases {
+            assert_eq!(date_at(&now, &offset), today);
+            assert_eq!(find_next_day_of_month(&today, 1), Some(next_first));
+            assert_eq!(find_next_weekday(&today, Weekday::Mon), Some(date(11, 6)));
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+use crate::{days_forward_to, find_next_annual_date, find_next_day_of_month, Recurrence};
+
+/// A start date with precomputed fields, for running many queries from the same date.
+///
+/// Dashboards rendering dozens of rules from the same "today" can create a `QueryContext` once
+/// and reuse it, instead of re-deriving the start date's weekday for every query. Every method
+/// returns the same result as the corresponding free function.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::QueryContext;
+///
+/// let context = QueryContext::new(NaiveDate::from_ymd_opt(2023, 10, 15).unwrap()); // A Sunday
+/// assert_eq!(
+///     context.next_weekday(Weekday::Mon),
+///     NaiveDate::from_ymd_opt(2023, 10, 16)
+/// );
+/// assert_eq!(
+///     context.next_day_of_month(20),
+///     NaiveDate::from_ymd_opt(2023, 10, 20)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QueryContext {
+    start: NaiveDate,
+    start_weekday: Weekday,
+}
+
+impl QueryContext {
+    /// Creates a context for queries starting from `start`.
+    pub fn new(start: NaiveDate) -> Self {
+        QueryContext {
+            start,
+            start_weekday: start.weekday(),
+        }
+    }
+
+    /// Returns the start date of the queries.
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    /// Returns the weekday of the start date.
+    pub fn start_weekday(&self) -> Weekday {
+        self.start_weekday
+    }
+
+    /// Equivalent to [`find_next_weekday`](crate::find_next_weekday) from the start date.
+    pub fn next_weekday(&self, weekday: Weekday) -> Option<NaiveDate> {
+        let days_distance = Days::new(days_forward_to(self.start_weekday, weekday).into());
+        self.start.checked_add_days(days_distance)
+    }
+
+    /// Equivalent to [`find_next_day_of_month`] from the start date.
+    pub fn next_day_of_month(&self, day: u32) -> Option<NaiveDate> {
+        find_next_day_of_month(&self.start, day)
+    }
+
+    /// Equivalent to [`find_next_annual_date`] from the start date.
+    pub fn next_annual_date(&self, month: u32, day: u32) -> Option<NaiveDate> {
+        find_next_annual_date(&self.start, month, day)
+    }
+
+    /// Equivalent to [`Recurrence::next`] from the start date.
+    pub fn next(&self, recurrence: &Recurrence) -> Option<NaiveDate> {
+        match *recurrence {
+            Recurrence::Weekly(weekday) => self.next_weekday(weekday),
+            _ => recurrence.next(&self.start),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::find_next_weekday;
+
+    #[test]
+    fn test_query_context_agrees_with_free_functions() {
+        let weekdays = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+
+        let mut start = NaiveDate::from_ymd_opt(2023, 12, 20).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        while start <= end {
+            let context = QueryContext::new(start);
+            assert_eq!(context.start(), start);
+            assert_eq!(context.start_weekday(), start.weekday());
+
+            for weekday in weekdays {
+                assert_eq!(
+                    context.next_weekday(weekday),
+                    find_next_weekday(&start, weekday)
+                );
+                let recurrence = Recurrence::Weekly(weekday);
+                assert_eq!(context.next(&recurrence), recurrence.next(&start));
+            }
+            for day in [1, 15, 29, 30, 31] {
+                assert_eq!(
+                    context.next_day_of_month(day),
+                    find_next_day_of_month(&start, day)
+                );
+            }
+            for (month, day) in [(1, 1), (2, 29), (12, 25)] {
+                assert_eq!(
+                    context.next_annual_date(month, day),
+                    find_next_annual_date(&start, month, day)
+                );
+            }
+
+            start = start.succ_opt().unwrap();
+        }
+    }
+}
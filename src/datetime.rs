@@ -0,0 +1,238 @@
+use chrono::{DateTime, Datelike, NaiveDateTime, NaiveTime, TimeDelta, TimeZone, Weekday};
+
+use crate::{find_next_day_of_month, find_next_weekday};
+
+/// Finds the next occurrence of a day of the month, keeping the wall-clock time of `now`.
+///
+/// The date is found with [`find_next_day_of_month`] from the local date of `now`, so a day that
+/// some months lack, such as the 31st, skips those months. The result has the same local time of
+/// day as `now`, in the same time zone. If a daylight saving time transition makes that time
+/// ambiguous on the resulting date, the earlier of the two instants is returned, and if the
+/// transition skips it, the time is moved forward by an hour.
+///
+/// # Arguments
+///
+/// * `now` - The starting date and time.
+/// * `day` - The target day of the month (1-31).
+///
+/// # Returns
+///
+/// An `Option<DateTime<Tz>>` containing the next matching date and time. Returns `None` if the
+/// day is invalid or if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{FixedOffset, TimeZone};
+/// use next_matching_day::find_next_day_of_month_dt;
+///
+/// let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+/// let now = tz.with_ymd_and_hms(2023, 1, 31, 9, 30, 0).unwrap();
+/// let result = find_next_day_of_month_dt(&now, 31);
+/// assert_eq!(result, tz.with_ymd_and_hms(2023, 3, 31, 9, 30, 0).single());
+/// ```
+pub fn find_next_day_of_month_dt<Tz: TimeZone>(
+    now: &DateTime<Tz>,
+    day: u32,
+) -> Option<DateTime<Tz>> {
+    let local = now.naive_local();
+    let date = find_next_day_of_month(&local.date(), day)?;
+    resolve_local(&now.timezone(), &date.and_time(local.time()))
+}
+
+/// Finds the end of the next local day falling on a weekday, counting today.
+///
+/// This is the deadline for "due by the end of Friday": if the local date of `now` falls on
+/// `weekday` the result is the end of today, and otherwise it is the end of the next such day, as
+/// found by [`find_next_weekday`]. The end of a day is its last second, which is 23:59:59 local
+/// time unless a daylight saving time transition happens at midnight. It is found as one second
+/// before the first instant of the following day, so it is never skipped and, when the end of
+/// the day repeats, it is the later of the two instants.
+///
+/// # Arguments
+///
+/// * `now` - The current date and time.
+/// * `weekday` - The target weekday.
+///
+/// # Returns
+///
+/// An `Option<DateTime<Tz>>` containing the end of the matching day. Returns `None` if the
+/// calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc, Weekday};
+/// use next_matching_day::find_next_weekday_end_of_day;
+///
+/// let tuesday = Utc.with_ymd_and_hms(2023, 10, 17, 9, 30, 0).unwrap();
+/// let result = find_next_weekday_end_of_day(&tuesday, &Weekday::Fri);
+/// assert_eq!(result, Utc.with_ymd_and_hms(2023, 10, 20, 23, 59, 59).single());
+/// ```
+pub fn find_next_weekday_end_of_day<Tz: TimeZone>(
+    now: &DateTime<Tz>,
+    weekday: &Weekday,
+) -> Option<DateTime<Tz>> {
+    let today = now.date_naive();
+    let date = if today.weekday() == *weekday {
+        today
+    } else {
+        find_next_weekday(&today, *weekday)?
+    };
+    let next_day = date.succ_opt()?.and_time(NaiveTime::MIN);
+    resolve_local(&now.timezone(), &next_day)?.checked_sub_signed(TimeDelta::seconds(1))
+}
+
+/// Converts a local date and time to an instant in `tz`, taking the earlier instant when the
+/// local time is ambiguous and moving it forward by an hour when it is skipped.
+fn resolve_local<Tz: TimeZone>(tz: &Tz, local: &NaiveDateTime) -> Option<DateTime<Tz>> {
+    tz.from_local_datetime(local).earliest().or_else(|| {
+        let shifted = local.checked_add_signed(TimeDelta::hours(1))?;
+        tz.from_local_datetime(&shifted).earliest()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::find_last_weekday_of_month;
+    use chrono::{FixedOffset, LocalResult, NaiveDate, Timelike};
+
+    /// A time zone at UTC+1 that moves to UTC+2 between 01:00 UTC on the last Sundays of March
+    /// and October, like Central European Time.
+    #[derive(Debug, Clone, Copy)]
+    struct CentralEurope;
+
+    impl CentralEurope {
+        const STANDARD: i32 = 3600;
+        const SUMMER: i32 = 7200;
+    }
+
+    impl TimeZone for CentralEurope {
+        type Offset = FixedOffset;
+
+        fn from_offset(_offset: &FixedOffset) -> Self {
+            CentralEurope
+        }
+
+        fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+            self.offset_from_local_datetime(&local.and_time(NaiveTime::MIN))
+        }
+
+        fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+            let offsets: Vec<_> = [Self::SUMMER, Self::STANDARD]
+                .into_iter()
+                .map(|secs| FixedOffset::east_opt(secs).unwrap())
+                .filter(|offset| {
+                    let utc = *local - TimeDelta::seconds(offset.local_minus_utc().into());
+                    self.offset_from_utc_datetime(&utc) == *offset
+                })
+                .collect();
+            match offsets[..] {
+                [offset] => LocalResult::Single(offset),
+                [earliest, latest] => LocalResult::Ambiguous(earliest, latest),
+                _ => LocalResult::None,
+            }
+        }
+
+        fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+            self.offset_from_utc_datetime(&utc.and_time(NaiveTime::MIN))
+        }
+
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+            let switch = |month| {
+                find_last_weekday_of_month(utc.year(), month, Weekday::Sun)
+                    .and_then(|date| date.and_hms_opt(1, 0, 0))
+                    .unwrap()
+            };
+            let summer = (switch(3)..switch(10)).contains(utc);
+            FixedOffset::east_opt(if summer { Self::SUMMER } else { Self::STANDARD }).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_find_next_day_of_month_dt() {
+        let at = |m, d, h, min| CentralEurope.with_ymd_and_hms(2024, m, d, h, min, 0);
+
+        // Test case 1: The time of day is preserved
+        let now = at(1, 15, 14, 30).unwrap();
+        let result = find_next_day_of_month_dt(&now, 20).unwrap();
+        assert_eq!(result, at(1, 20, 14, 30).unwrap());
+        assert_eq!(result.time(), now.time());
+
+        // Test case 2: Day 31 skips February, landing after the switch to summer time
+        let now = at(1, 31, 9, 15).unwrap();
+        let result = find_next_day_of_month_dt(&now, 31).unwrap();
+        assert_eq!(result, at(3, 31, 9, 15).unwrap());
+        assert_eq!(result.offset().local_minus_utc(), CentralEurope::SUMMER);
+
+        // Test case 3: A time skipped by the switch to summer time moves forward an hour
+        let now = at(2, 10, 2, 30).unwrap();
+        let result = find_next_day_of_month_dt(&now, 31).unwrap();
+        assert_eq!(result, at(3, 31, 3, 30).unwrap());
+
+        // Test case 4: A time repeated by the switch back to standard time is the earlier one
+        let now = at(10, 1, 2, 30).unwrap();
+        let result = find_next_day_of_month_dt(&now, 27).unwrap();
+        assert!(matches!(at(10, 27, 2, 30), LocalResult::Ambiguous(..)));
+        assert_eq!(result, at(10, 27, 2, 30).earliest().unwrap());
+        assert_eq!(result.offset().local_minus_utc(), CentralEurope::SUMMER);
+
+        // Test case 5: The local date is used, not the UTC date
+        let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+        let now = tz.with_ymd_and_hms(2024, 4, 30, 22, 0, 0).unwrap(); // May 1st in UTC
+        let result = find_next_day_of_month_dt(&now, 1).unwrap();
+        assert_eq!(result, tz.with_ymd_and_hms(2024, 5, 1, 22, 0, 0).unwrap());
+        assert_eq!((result.day(), result.hour()), (1, 22));
+
+        // Test case 6: An invalid day
+        assert_eq!(find_next_day_of_month_dt(&now, 32), None);
+    }
+
+    #[test]
+    fn test_find_next_weekday_end_of_day() {
+        let at = |m, d, h, min, sec| CentralEurope.with_ymd_and_hms(2024, m, d, h, min, sec);
+        let end_of_day =
+            |result: &DateTime<CentralEurope>| (result.hour(), result.minute(), result.second());
+
+        // Test case 1: The end of a later day is 23:59:59
+        let now = at(1, 16, 9, 30, 0).unwrap(); // A Tuesday
+        let result = find_next_weekday_end_of_day(&now, &Weekday::Fri).unwrap();
+        assert_eq!(result, at(1, 19, 23, 59, 59).unwrap());
+        assert_eq!(end_of_day(&result), (23, 59, 59));
+
+        // Test case 2: Today counts, up to its last second
+        let result = find_next_weekday_end_of_day(&now, &Weekday::Tue).unwrap();
+        assert_eq!(result, at(1, 16, 23, 59, 59).unwrap());
+        let now = at(1, 16, 23, 59, 59).unwrap();
+        assert_eq!(find_next_weekday_end_of_day(&now, &Weekday::Tue), Some(now));
+        let result = find_next_weekday_end_of_day(&now, &Weekday::Mon).unwrap();
+        assert_eq!(result, at(1, 22, 23, 59, 59).unwrap());
+
+        // Test case 3: A day shortened by the switch to summer time still ends at 23:59:59
+        let now = at(3, 27, 12, 0, 0).unwrap(); // A Wednesday in standard time
+        let result = find_next_weekday_end_of_day(&now, &Weekday::Sun).unwrap();
+        assert_eq!(result, at(3, 31, 23, 59, 59).unwrap());
+        assert_eq!(end_of_day(&result), (23, 59, 59));
+        assert_eq!(result.offset().local_minus_utc(), CentralEurope::SUMMER);
+        let start = at(3, 31, 0, 0, 0).unwrap();
+        assert_eq!((result - start).num_seconds(), 23 * 3600 - 1);
+
+        // Test case 4: A day lengthened by the switch back to standard time
+        let now = at(10, 24, 12, 0, 0).unwrap(); // A Thursday in summer time
+        let result = find_next_weekday_end_of_day(&now, &Weekday::Sun).unwrap();
+        assert_eq!(end_of_day(&result), (23, 59, 59));
+        assert_eq!(result.offset().local_minus_utc(), CentralEurope::STANDARD);
+        let start = at(10, 27, 0, 0, 0).unwrap();
+        assert_eq!((result - start).num_seconds(), 25 * 3600 - 1);
+
+        // Test case 5: The local date is used, not the UTC date
+        let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+        let now = tz.with_ymd_and_hms(2024, 1, 16, 8, 0, 0).unwrap(); // Monday in UTC
+        let result = find_next_weekday_end_of_day(&now, &Weekday::Tue);
+        assert_eq!(
+            result,
+            tz.with_ymd_and_hms(2024, 1, 16, 23, 59, 59).single()
+        );
+    }
+}
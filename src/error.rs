@@ -0,0 +1,46 @@
+use std::fmt;
+
+/// An error describing why a date could not be computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NextDayError {
+    /// The month is not between 1 and 12.
+    InvalidMonth(u32),
+    /// The day can never occur, either because it is not between 1 and 31 or because the month
+    /// never has that many days.
+    InvalidDay(u32),
+    /// The year is outside the range supported by `NaiveDate`.
+    InvalidYear(i32),
+    /// The interval between occurrences is 0.
+    InvalidInterval(u32),
+    /// The occurrence of a weekday within a month is not between 1 and 5.
+    InvalidOccurrence(u32),
+    /// The inputs are valid, but no matching date was found within the search window.
+    NotFoundWithinWindow,
+}
+
+impl fmt::Display for NextDayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NextDayError::InvalidMonth(month) => {
+                write!(f, "invalid month {month}, expected 1-12")
+            }
+            NextDayError::InvalidDay(day) => write!(f, "invalid day {day}"),
+            NextDayError::InvalidYear(year) => write!(f, "year {year} is out of range"),
+            NextDayError::InvalidInterval(n) => {
+                write!(f, "invalid interval {n}, expected at least 1")
+            }
+            NextDayError::InvalidOccurrence(n) => {
+                write!(
+                    f,
+                    "invalid occurrence {n} of a weekday in a month, expected 1-5"
+                )
+            }
+            NextDayError::NotFoundWithinWindow => {
+                write!(f, "no matching date found within the search window")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NextDayError {}
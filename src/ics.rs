@@ -0,0 +1,105 @@
+use chrono::NaiveDate;
+
+use crate::Recurrence;
+
+impl Recurrence {
+    /// Formats the next `count` occurrences after `from` as iCalendar `VEVENT` entries.
+    ///
+    /// Each occurrence becomes one all-day event titled `summary`, with a `DTSTART` on the
+    /// occurrence and a `DTEND` on the following day. Lines end with `CRLF` as iCalendar requires,
+    /// and the summary is escaped. This is a minimal export rather than a full RFC 5545
+    /// implementation: the events have no `UID` or `DTSTAMP` and are not wrapped in a
+    /// `VCALENDAR`, which is left to the caller.
+    ///
+    /// This method is only available with the `ics` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use next_matching_day::Recurrence;
+    ///
+    /// let from = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
+    /// let ics = Recurrence::Weekly(Weekday::Mon).to_ics_events(&from, 1, "Standup");
+    /// assert_eq!(
+    ///     ics,
+    ///     "BEGIN:VEVENT\r\n\
+    ///      DTSTART;VALUE=DATE:20231016\r\n\
+    ///      DTEND;VALUE=DATE:20231017\r\n\
+    ///      SUMMARY:Standup\r\n\
+    ///      END:VEVENT\r\n"
+    /// );
+    /// ```
+    pub fn to_ics_events(&self, from: &NaiveDate, count: usize, summary: &str) -> String {
+        let summary = escape_text(summary);
+        let mut ics = String::new();
+        for date in self.occurrences(from).take(count) {
+            let Some(end) = date.succ_opt() else {
+                break;
+            };
+            ics.push_str(&format!(
+                "BEGIN:VEVENT\r\n\
+                 DTSTART;VALUE=DATE:{}\r\n\
+                 DTEND;VALUE=DATE:{}\r\n\
+                 SUMMARY:{summary}\r\n\
+                 END:VEVENT\r\n",
+                date.format("%Y%m%d"),
+                end.format("%Y%m%d"),
+            ));
+        }
+        ics
+    }
+}
+
+/// Escapes the characters that have a special meaning in an iCalendar text value.
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Weekday;
+
+    #[test]
+    fn test_recurrence_to_ics_events() {
+        let from = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+
+        // Test case 1: One event per occurrence, with the occurrence as the start date
+        let ics = Recurrence::DayOfMonth(31).to_ics_events(&from, 3, "Rent");
+        assert_eq!(ics.matches("BEGIN:VEVENT\r\n").count(), 3);
+        assert_eq!(ics.matches("END:VEVENT\r\n").count(), 3);
+        let starts: Vec<_> = ics
+            .lines()
+            .filter_map(|line| line.strip_prefix("DTSTART;VALUE=DATE:"))
+            .map(str::trim_end)
+            .collect();
+        assert_eq!(starts, ["20230131", "20230331", "20230531"]);
+        assert!(ics.contains("DTEND;VALUE=DATE:20230201\r\n"));
+        assert!(ics.ends_with("END:VEVENT\r\n"));
+
+        // Test case 2: The summary is escaped
+        let ics = Recurrence::Weekly(Weekday::Mon).to_ics_events(&from, 1, "Plan; review, ship");
+        assert!(ics.contains("SUMMARY:Plan\\; review\\, ship\r\n"));
+        assert_eq!(escape_text("a\\b\r\nc"), "a\\\\b\\nc");
+
+        // Test case 3: No occurrences give no events
+        assert_eq!(
+            Recurrence::Weekly(Weekday::Mon).to_ics_events(&from, 0, "x"),
+            ""
+        );
+        assert_eq!(Recurrence::DayOfMonth(32).to_ics_events(&from, 3, "x"), "");
+    }
+}
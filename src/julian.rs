@@ -0,0 +1,280 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::find_next_weekday;
+
+/// The Julian day number of 0001-01-01 in the proleptic Gregorian calendar.
+const JULIAN_DAY_OF_CE: i32 = 1_721_425;
+
+fn is_julian_leap_year(year: i32) -> bool {
+    year.rem_euclid(4) == 0
+}
+
+fn days_in_julian_month(year: i32, month: u32) -> Option<u32> {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        4 | 6 | 9 | 11 => Some(30),
+        2 if is_julian_leap_year(year) => Some(29),
+        2 => Some(28),
+        _ => None,
+    }
+}
+
+/// A date in the Julian calendar, for historical dates before the Gregorian reform.
+///
+/// The Julian calendar has a leap year every 4 years, without the Gregorian exception for
+/// century years, so it drifts from the Gregorian calendar by about three days every four
+/// centuries: Thursday 4 October 1582 (Julian) was followed by Friday 15 October 1582
+/// (Gregorian). A `JulianDate` names the same day as the [`NaiveDate`] returned by
+/// [`to_gregorian`](JulianDate::to_gregorian), so both have the same weekday, while the year,
+/// month and day fields follow the Julian rules.
+///
+/// This type is only available with the `julian` feature, which is disabled by default.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::JulianDate;
+///
+/// let date = JulianDate::from_ymd_opt(1582, 10, 4).unwrap();
+/// assert_eq!(date.weekday(), Weekday::Thu);
+/// assert_eq!(date.to_gregorian(), NaiveDate::from_ymd_opt(1582, 10, 14).unwrap());
+///
+/// // 1900 is a leap year in the Julian calendar.
+/// assert!(JulianDate::from_ymd_opt(1900, 2, 29).is_some());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JulianDate(NaiveDate);
+
+impl JulianDate {
+    /// Creates a date from a Julian year, month and day.
+    ///
+    /// Returns `None` if the month or day is invalid under the Julian rules, or if the date is
+    /// outside the range supported by `NaiveDate`.
+    pub fn from_ymd_opt(year: i32, month: u32, day: u32) -> Option<Self> {
+        if day == 0 || day > days_in_julian_month(year, month)? {
+            return None;
+        }
+
+        // Count from March, so that the leap day is the last day of the counted year.
+        let years_from_march = i64::from(year) + 4800 - i64::from(month <= 2);
+        let month_from_march = i64::from((month + 9) % 12);
+        let julian_day = i64::from(day)
+            + (153 * month_from_march + 2) / 5
+            + 365 * years_from_march
+            + years_from_march.div_euclid(4)
+            - 32083;
+        let days_from_ce = i32::try_from(julian_day - i64::from(JULIAN_DAY_OF_CE)).ok()?;
+        NaiveDate::from_num_days_from_ce_opt(days_from_ce).map(JulianDate)
+    }
+
+    /// Returns the Julian date of the same day as a Gregorian date.
+    pub fn from_gregorian(date: NaiveDate) -> Self {
+        JulianDate(date)
+    }
+
+    /// Returns the Gregorian date of the same day.
+    pub fn to_gregorian(&self) -> NaiveDate {
+        self.0
+    }
+
+    /// Returns the Julian year, month and day.
+    fn ymd(&self) -> (i32, u32, u32) {
+        let julian_day = i64::from(self.0.num_days_from_ce()) + i64::from(JULIAN_DAY_OF_CE);
+        let c = julian_day + 32082;
+        let years_from_march = (4 * c + 3).div_euclid(1461);
+        let day_of_year = c - (1461 * years_from_march).div_euclid(4);
+        let month_from_march = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+        let month = (month_from_march + 2) % 12 + 1;
+        let year = years_from_march - 4800 + month_from_march / 10;
+        (year as i32, month as u32, day as u32)
+    }
+
+    /// Returns the Julian year.
+    pub fn year(&self) -> i32 {
+        self.ymd().0
+    }
+
+    /// Returns the Julian month (1-12).
+    pub fn month(&self) -> u32 {
+        self.ymd().1
+    }
+
+    /// Returns the Julian day of the month (1-31).
+    pub fn day(&self) -> u32 {
+        self.ymd().2
+    }
+
+    /// Returns the weekday, which is the same as that of the Gregorian date of the same day.
+    pub fn weekday(&self) -> Weekday {
+        self.0.weekday()
+    }
+
+    /// Finds the next date strictly after this one whose Julian day of the month is `day`.
+    ///
+    /// This is the Julian counterpart of [`find_next_day_of_month`](crate::find_next_day_of_month):
+    /// months without the day are skipped, using the Julian lengths of February.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<JulianDate>` containing the next matching date. Returns `None` if the day is
+    /// not between 1 and 31 or if the calculation overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use next_matching_day::JulianDate;
+    ///
+    /// // February 1500 has a 29th in the Julian calendar, but not in the Gregorian one.
+    /// let date = JulianDate::from_ymd_opt(1500, 2, 1).unwrap();
+    /// assert_eq!(date.next_day_of_month(29), JulianDate::from_ymd_opt(1500, 2, 29));
+    /// ```
+    pub fn next_day_of_month(&self, day: u32) -> Option<JulianDate> {
+        if !(1..=31).contains(&day) {
+            return None;
+        }
+
+        let (mut year, mut month, current_day) = self.ymd();
+        if day > current_day {
+            if let Some(date) = JulianDate::from_ymd_opt(year, month, day) {
+                return Some(date);
+            }
+        }
+        // Every day of the month occurs at least once in any two consecutive months.
+        for _ in 0..2 {
+            (year, month) = if month == 12 {
+                (year.checked_add(1)?, 1)
+            } else {
+                (year, month + 1)
+            };
+            if let Some(date) = JulianDate::from_ymd_opt(year, month, day) {
+                return Some(date);
+            }
+        }
+        None
+    }
+}
+
+/// The Julian calendar counterpart of [`find_next_weekday`].
+///
+/// Weekdays do not depend on the calendar, so the result is the same day as
+/// [`find_next_weekday`] returns for the Gregorian date of `date`.
+///
+/// This function is only available with the `julian` feature, which is disabled by default.
+///
+/// # Returns
+///
+/// An `Option<JulianDate>` containing the next date with the given weekday. Returns `None` if
+/// the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use next_matching_day::{find_next_weekday_julian, JulianDate};
+///
+/// // The last day of the Julian calendar in Rome was a Thursday.
+/// let date = JulianDate::from_ymd_opt(1582, 10, 4).unwrap();
+/// let next_sunday = find_next_weekday_julian(&date, Weekday::Sun).unwrap();
+/// assert_eq!(next_sunday, JulianDate::from_ymd_opt(1582, 10, 7).unwrap());
+/// ```
+pub fn find_next_weekday_julian(date: &JulianDate, weekday: Weekday) -> Option<JulianDate> {
+    find_next_weekday(&date.0, weekday).map(JulianDate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn julian(y: i32, m: u32, d: u32) -> JulianDate {
+        JulianDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn gregorian(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_julian_date_conversion() {
+        // Test case 1: Known Julian and Gregorian dates of the same day
+        for (julian_date, gregorian_date) in [
+            (julian(1582, 10, 4), gregorian(1582, 10, 14)),
+            (julian(1582, 10, 5), gregorian(1582, 10, 15)),
+            (julian(1, 1, 3), gregorian(1, 1, 1)),
+            (julian(1900, 2, 29), gregorian(1900, 3, 13)),
+            (julian(2023, 12, 25), gregorian(2024, 1, 7)),
+        ] {
+            assert_eq!(julian_date.to_gregorian(), gregorian_date);
+            assert_eq!(JulianDate::from_gregorian(gregorian_date), julian_date);
+        }
+
+        // Test case 2: The fields round trip over several centuries
+        let mut date = gregorian(1400, 1, 1);
+        while date < gregorian(2100, 1, 1) {
+            let julian_date = JulianDate::from_gregorian(date);
+            let (y, m, d) = (julian_date.year(), julian_date.month(), julian_date.day());
+            assert_eq!(JulianDate::from_ymd_opt(y, m, d), Some(julian_date));
+            date = date.succ_opt().unwrap();
+        }
+
+        // Test case 3: Julian leap rules
+        assert!(JulianDate::from_ymd_opt(1900, 2, 29).is_some());
+        assert!(JulianDate::from_ymd_opt(1901, 2, 29).is_none());
+        assert!(JulianDate::from_ymd_opt(-1, 2, 29).is_none());
+        assert!(JulianDate::from_ymd_opt(-4, 2, 29).is_some());
+        assert!(JulianDate::from_ymd_opt(2023, 4, 31).is_none());
+        assert!(JulianDate::from_ymd_opt(2023, 13, 1).is_none());
+        assert!(JulianDate::from_ymd_opt(2023, 1, 0).is_none());
+    }
+
+    #[test]
+    fn test_julian_date_weekday() {
+        // Test case 1: The same numbered date falls on different weekdays in both calendars
+        let date = julian(1582, 10, 4);
+        assert_eq!(date.weekday(), Weekday::Thu);
+        assert_eq!(gregorian(1582, 10, 4).weekday(), Weekday::Mon);
+
+        // Test case 2: The day after the reform
+        assert_eq!(gregorian(1582, 10, 15).weekday(), Weekday::Fri);
+        assert_eq!(
+            date.to_gregorian().succ_opt(),
+            Some(gregorian(1582, 10, 15))
+        );
+    }
+
+    #[test]
+    fn test_find_next_weekday_julian() {
+        // Test case 1: Across the end of a Julian month
+        let date = julian(1582, 10, 30); // Tuesday
+        assert_eq!(
+            find_next_weekday_julian(&date, Weekday::Thu),
+            Some(julian(1582, 11, 1))
+        );
+
+        // Test case 2: The same weekday is a week later
+        assert_eq!(
+            find_next_weekday_julian(&date, Weekday::Tue),
+            Some(julian(1582, 11, 6))
+        );
+    }
+
+    #[test]
+    fn test_julian_date_next_day_of_month() {
+        // Test case 1: Later in the same month
+        let date = julian(1500, 2, 1);
+        assert_eq!(date.next_day_of_month(29), Some(julian(1500, 2, 29)));
+
+        // Test case 2: Julian common years skip February for day 29
+        let date = julian(1501, 1, 30);
+        assert_eq!(date.next_day_of_month(29), Some(julian(1501, 3, 29)));
+
+        // Test case 3: Day 31 across the end of the year
+        let date = julian(1582, 12, 31);
+        assert_eq!(date.next_day_of_month(31), Some(julian(1583, 1, 31)));
+
+        // Test case 4: Invalid days
+        assert_eq!(date.next_day_of_month(0), None);
+        assert_eq!(date.next_day_of_month(32), None);
+    }
+}
@@ -1,5 +1,70 @@
 use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
 
+mod between;
+mod business;
+#[cfg(feature = "clock")]
+mod clock;
+mod context;
+mod datetime;
+mod error;
+#[cfg(feature = "ics")]
+mod ics;
+#[cfg(feature = "julian")]
+mod julian;
+mod overflow;
+mod recurrence;
+mod rotation;
+mod season;
+#[cfg(feature = "time")]
+mod time_compat;
+
+pub use between::Between;
+pub use business::{
+    add_business_days, count_business_days, is_business_day, subtract_business_days,
+    CompositeCalendar, HolidayCalendar, NoHolidays, WeekendRule,
+};
+#[cfg(feature = "clock")]
+pub use clock::{today_in, today_local, today_utc};
+pub use context::QueryContext;
+pub use datetime::{find_next_day_of_month_dt, find_next_weekday_end_of_day};
+pub use error::NextDayError;
+#[cfg(feature = "julian")]
+pub use julian::{find_next_weekday_julian, JulianDate};
+pub use overflow::{same_day_in_month, DayOverflow};
+pub use recurrence::{
+    coincidences_between, filter_recurrence, find_next_first_of, infer_recurrence, merge_next,
+    nearest_across, next_across, next_for_each, Occurrences, ParseRecurrenceError, Recurrence,
+    RecurrenceCursor, RecurrenceStep,
+};
+pub use rotation::RotationSchedule;
+pub use season::{find_next_season_start, season_of, Hemisphere, Season};
+#[cfg(feature = "time")]
+pub use time_compat::{
+    find_next_annual_date_time, find_next_day_of_month_time, find_next_weekday_time,
+};
+
+/// Returns the number of days to move forward from `from` to reach the next `target` weekday.
+///
+/// The result is always between 1 and 7: moving from a weekday to the same weekday takes a full
+/// week, since every "next" query in this crate is strictly after its starting date. This is the
+/// single place where the weekday gap is computed, so that all weekday arithmetic in the crate
+/// shares the same phase, independent of which weekday a week is considered to start on.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use next_matching_day::days_forward_to;
+///
+/// assert_eq!(days_forward_to(Weekday::Sun, Weekday::Mon), 1);
+/// assert_eq!(days_forward_to(Weekday::Mon, Weekday::Sun), 6);
+/// assert_eq!(days_forward_to(Weekday::Mon, Weekday::Mon), 7);
+/// ```
+pub fn days_forward_to(from: Weekday, target: Weekday) -> u32 {
+    let days_since = target.days_since(from);
+    (days_since + 6) % 7 + 1
+}
+
 /// Calculates the next date that falls on a specific weekday.
 ///
 /// If the current date is already on the desired weekday, it returns the date of the same weekday in the next week.
@@ -7,7 +72,7 @@ use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
 /// # Arguments
 ///
 /// * `current_date` - The starting date.
-/// * `next_weekday` - The target weekday.
+/// * `weekday` - The target weekday.
 ///
 /// # Examples
 ///
@@ -17,12 +82,12 @@ use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
 ///
 /// // Starting from a Sunday, the next Monday is the next day.
 /// let current_date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // A Sunday
-/// let next_monday = find_next_weekday(&current_date, &Weekday::Mon).unwrap();
+/// let next_monday = find_next_weekday(&current_date, Weekday::Mon).unwrap();
 /// assert_eq!(next_monday, NaiveDate::from_ymd_opt(2023, 10, 16).unwrap());
 ///
 /// // If it's already Monday, it returns the Monday of the next week.
 /// let current_date = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(); // A Monday
-/// let next_monday = find_next_weekday(&current_date, &Weekday::Mon).unwrap();
+/// let next_monday = find_next_weekday(&current_date, Weekday::Mon).unwrap();
 /// assert_eq!(next_monday, NaiveDate::from_ymd_opt(2023, 10, 23).unwrap());
 /// ```
 ///
@@ -30,12 +95,23 @@ use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
 ///
 /// An `Option<NaiveDate>` which is the next date with the given weekday.
 /// Returns `None` if the calculation overflows, which is highly unlikely with `NaiveDate`.
-pub fn find_next_weekday(current_date: &NaiveDate, next_weekday: &Weekday) -> Option<NaiveDate> {
-    let days_since = next_weekday.days_since(current_date.weekday());
-    let days_distance = Days::new(((days_since + 6) % 7 + 1).into());
+pub fn find_next_weekday(current_date: &NaiveDate, weekday: Weekday) -> Option<NaiveDate> {
+    let days_distance = Days::new(days_forward_to(current_date.weekday(), weekday).into());
     current_date.checked_add_days(days_distance)
 }
 
+/// Calculates the next date that falls on a specific weekday, taking the weekday by reference.
+///
+/// This is the signature [`find_next_weekday`] had in earlier versions, kept so that existing
+/// callers keep compiling. It forwards to [`find_next_weekday`].
+#[deprecated(note = "pass the weekday by value to `find_next_weekday`")]
+pub fn find_next_weekday_ref(
+    current_date: &NaiveDate,
+    next_weekday: &Weekday,
+) -> Option<NaiveDate> {
+    find_next_weekday(current_date, *next_weekday)
+}
+
 /// Finds the next date with a specific day of the month.
 ///
 /// This function searches for the next occurrence of a given day of the month.
@@ -83,6 +159,7 @@ pub fn find_next_day_of_month(current_date: &NaiveDate, next_day: u32) -> Option
             .checked_add_months(Months::new(i))
             .and_then(|d| d.with_day(next_day))
         {
+            debug_assert!(date > *current_date);
             return Some(date);
         }
     }
@@ -97,6 +174,8 @@ pub fn find_next_day_of_month(current_date: &NaiveDate, next_day: u32) -> Option
 /// searches for the first valid occurrence in the subsequent years.
 ///
 /// This approach correctly handles cases like leap years when searching for February 29.
+/// Years follow the proleptic Gregorian calendar, so year 0 (1 BCE) is a leap year and negative
+/// years use the same divisible-by-4, 100 and 400 rules as positive ones.
 ///
 /// # Arguments
 ///
@@ -144,6 +223,7 @@ pub fn find_next_annual_date(
     // We check up to 8 years ahead, which is sufficient to find the next leap year.
     for i in 1..=8 {
         if let Some(date) = NaiveDate::from_ymd_opt(cur_year + i, next_month, next_day) {
+            debug_assert!(date > *current_date);
             return Some(date);
         }
     }
@@ -151,117 +231,4937 @@ pub fn find_next_annual_date(
     None
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use chrono::{NaiveDate, Weekday};
+/// Finds the next date sharing the current date's weekday and having the requested day-of-month parity.
+///
+/// The search starts one week after `current_date` and advances a week at a time. Within a month the
+/// parity of the day flips every week, but crossing into a new month can keep it unchanged, so more
+/// than one week may be skipped before the parity aligns.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date. Its weekday is the target weekday.
+/// * `even` - `true` to look for an even day of the month, `false` for an odd one.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next matching date. Returns `None` if no match is found
+/// within the next 8 weeks, which cannot happen unless the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::find_next_same_weekday_with_day_parity;
+///
+/// // From Tuesday, January 17th, the next Tuesday on an odd day is January 31st.
+/// let current_date = NaiveDate::from_ymd_opt(2023, 1, 17).unwrap();
+/// let result = find_next_same_weekday_with_day_parity(&current_date, false).unwrap();
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 1, 31).unwrap());
+/// ```
+pub fn find_next_same_weekday_with_day_parity(
+    current_date: &NaiveDate,
+    even: bool,
+) -> Option<NaiveDate> {
+    for i in 1..=8 {
+        let date = current_date.checked_add_days(Days::new(7 * i))?;
+        if (date.day() % 2 == 0) == even {
+            return Some(date);
+        }
+    }
 
-    #[test]
-    fn test_find_next_weekday() {
-        // Test case 1: Next weekday is the next day
-        let date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // Sunday
-        let result = find_next_weekday(&date, &Weekday::Mon).unwrap(); // Next Monday
-        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 16).unwrap());
+    None
+}
 
-        // Test case 2: Next weekday is in the next week
-        let date = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(); // Monday
-        let result = find_next_weekday(&date, &Weekday::Sun).unwrap(); // Next Sunday
-        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 22).unwrap());
+/// Calculates the next date that falls on a weekday given by name.
+///
+/// The name is matched case-insensitively against full weekday names (e.g. `"Monday"`) and
+/// three-letter abbreviations (e.g. `"mon"`), then the search is delegated to [`find_next_weekday`].
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `name` - The name of the target weekday.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` which is the next date with the named weekday.
+/// Returns `None` if the name is not a recognized weekday or if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::find_next_weekday_by_name;
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // A Sunday
+/// let next_monday = find_next_weekday_by_name(&current_date, "monday").unwrap();
+/// assert_eq!(next_monday, NaiveDate::from_ymd_opt(2023, 10, 16).unwrap());
+///
+/// assert_eq!(find_next_weekday_by_name(&current_date, "someday"), None);
+/// ```
+pub fn find_next_weekday_by_name(current_date: &NaiveDate, name: &str) -> Option<NaiveDate> {
+    let weekday = name.trim().parse::<Weekday>().ok()?;
+    find_next_weekday(current_date, weekday)
+}
 
-        // Test case 3: Next weekday is the same day
-        let date = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(); // Monday
-        let result = find_next_weekday(&date, &Weekday::Mon).unwrap(); // Next Monday
-        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 23).unwrap());
+/// Calculates the previous date that falls on a specific weekday.
+///
+/// This is the backward counterpart of [`find_next_weekday`]. If the current date is already on
+/// the desired weekday, it returns the date of the same weekday in the previous week.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `weekday` - The target weekday.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` which is the previous date with the given weekday.
+/// Returns `None` if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_previous_weekday;
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(); // A Monday
+/// let previous_sunday = find_previous_weekday(&current_date, Weekday::Sun).unwrap();
+/// assert_eq!(previous_sunday, NaiveDate::from_ymd_opt(2023, 10, 15).unwrap());
+/// ```
+pub fn find_previous_weekday(current_date: &NaiveDate, weekday: Weekday) -> Option<NaiveDate> {
+    let days_distance = Days::new(days_forward_to(weekday, current_date.weekday()).into());
+    current_date.checked_sub_days(days_distance)
+}
 
-        // Test case 4: Next weekday is in the next month
-        let date = NaiveDate::from_ymd_opt(2023, 10, 30).unwrap(); // Monday
-        let result = find_next_weekday(&date, &Weekday::Sun).unwrap(); // Next Sunday
-        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 5).unwrap());
+/// Returns an iterator over the dates falling on `weekday` after `start`, in ascending order.
+///
+/// The first item is [`find_next_weekday`] of `start`, and each following item is 7 days later.
+/// The iterator ends if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::weekday_iter;
+///
+/// let start = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // A Sunday
+/// let mondays: Vec<_> = weekday_iter(start, Weekday::Mon).take(2).collect();
+/// assert_eq!(
+///     mondays,
+///     [
+///         NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(),
+///         NaiveDate::from_ymd_opt(2023, 10, 23).unwrap(),
+///     ]
+/// );
+/// ```
+pub fn weekday_iter(start: NaiveDate, weekday: Weekday) -> impl Iterator<Item = NaiveDate> {
+    std::iter::successors(find_next_weekday(&start, weekday), |date| {
+        date.checked_add_days(Days::new(7))
+    })
+}
 
-        // Test case 5: Next weekday is in the next year
-        let date = NaiveDate::from_ymd_opt(2023, 12, 28).unwrap(); // Thursday
-        let result = find_next_weekday(&date, &Weekday::Wed).unwrap(); // Next Wednesday
-        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 1, 3).unwrap());
+/// Returns an iterator over the dates falling on `weekday` before `start`, in descending order.
+///
+/// The first item is [`find_previous_weekday`] of `start`, and each following item is 7 days
+/// earlier. Together with [`weekday_iter`] this allows rendering a window of dates around `start`.
+/// The iterator ends if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::weekday_iter_rev;
+///
+/// let start = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // A Sunday
+/// let mondays: Vec<_> = weekday_iter_rev(start, Weekday::Mon).take(2).collect();
+/// assert_eq!(
+///     mondays,
+///     [
+///         NaiveDate::from_ymd_opt(2023, 10, 9).unwrap(),
+///         NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(),
+///     ]
+/// );
+/// ```
+pub fn weekday_iter_rev(start: NaiveDate, weekday: Weekday) -> impl Iterator<Item = NaiveDate> {
+    std::iter::successors(find_previous_weekday(&start, weekday), |date| {
+        date.checked_sub_days(Days::new(7))
+    })
+}
 
-        // Test case 6: Leap year, before Feb 29
-        let date = NaiveDate::from_ymd_opt(2024, 2, 26).unwrap(); // Monday
-        let result = find_next_weekday(&date, &Weekday::Wed).unwrap(); // Next Wednesday
-        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 2, 28).unwrap());
+/// Finds the soonest upcoming date from a set of annual (month, day) pairs.
+///
+/// Each pair is resolved with [`find_next_annual_date`], so dates that have already passed this
+/// year roll into the following year, and February 29 resolves to the next leap year.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `dates` - The annual dates as `(month, day)` pairs.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the soonest matching date strictly after `current_date`.
+/// Returns `None` if `dates` is empty or if any pair is not a valid month and day (e.g. `(2, 30)`).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::find_next_from_annual_set;
+///
+/// // The English quarter days.
+/// let quarter_days = [(3, 25), (6, 24), (9, 29), (12, 25)];
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 7, 1).unwrap();
+/// let result = find_next_from_annual_set(&current_date, &quarter_days).unwrap();
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 9, 29).unwrap());
+/// ```
+pub fn find_next_from_annual_set(
+    current_date: &NaiveDate,
+    dates: &[(u32, u32)],
+) -> Option<NaiveDate> {
+    let mut soonest: Option<NaiveDate> = None;
 
-        // Test case 7: Leap year, on Feb 28
-        let date = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap(); // Wednesday
-        let result = find_next_weekday(&date, &Weekday::Thu).unwrap(); // Next Thursday
-        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+    for &(month, day) in dates {
+        // Validate against a leap year so that February 29 is accepted.
+        NaiveDate::from_ymd_opt(2000, month, day)?;
 
-        // Test case 8: Leap year, on Feb 29
-        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(); // Thursday
-        let result = find_next_weekday(&date, &Weekday::Fri).unwrap(); // Next Friday
-        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 3, 1).unwrap());
+        let date = find_next_annual_date(current_date, month, day)?;
+        if soonest.is_none_or(|soonest| date < soonest) {
+            soonest = Some(date);
+        }
     }
 
-    #[test]
-    fn test_find_next_day_of_month() {
-        // Test case 1: Next day is in the same month
-        let date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
-        let result = find_next_day_of_month(&date, 20).unwrap();
-        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 20).unwrap());
+    soonest
+}
 
-        // Test case 2: Next day is in the next month
-        let date = NaiveDate::from_ymd_opt(2023, 10, 25).unwrap();
-        let result = find_next_day_of_month(&date, 10).unwrap();
-        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 10).unwrap());
+/// Finds the previous date with a specific day of the month.
+///
+/// This is the backward counterpart of [`find_next_day_of_month`]. If the day has already passed
+/// in the current month, it returns the date in the current month. Otherwise, it searches for the
+/// most recent previous month that has that day.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `previous_day` - The target day of the month (1-31).
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the previous matching date. Returns `None` if the
+/// day is invalid (e.g., greater than 31) or if a valid date cannot be found
+/// within a reasonable number of past months (currently 12).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::find_previous_day_of_month;
+///
+/// // Find the previous 31st from March 30th -> January 31st (skipping February)
+/// let current_date = NaiveDate::from_ymd_opt(2023, 3, 30).unwrap();
+/// let previous_31st = find_previous_day_of_month(&current_date, 31).unwrap();
+/// assert_eq!(previous_31st, NaiveDate::from_ymd_opt(2023, 1, 31).unwrap());
+/// ```
+pub fn find_previous_day_of_month(
+    current_date: &NaiveDate,
+    previous_day: u32,
+) -> Option<NaiveDate> {
+    // If the day has already passed in the current month, use it.
+    if current_date.day() > previous_day {
+        if let Some(date) = current_date.with_day(previous_day) {
+            return Some(date);
+        }
+    }
 
-        // Test case 3: Current day is the same as next_day, should find it in the next month
-        let date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
-        let result = find_next_day_of_month(&date, 15).unwrap();
-        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 15).unwrap());
+    // Otherwise, check preceding months.
+    for i in 1..=12 {
+        if let Some(date) = current_date
+            .checked_sub_months(Months::new(i))
+            .and_then(|d| d.with_day(previous_day))
+        {
+            return Some(date);
+        }
+    }
 
-        // Test case 4: Next day is 31, current month is short (September)
-        let date = NaiveDate::from_ymd_opt(2023, 9, 15).unwrap();
-        let result = find_next_day_of_month(&date, 31).unwrap();
-        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 31).unwrap());
+    None
+}
 
-        // Test case 5: Next day is 31, current month is January, next month is February (short)
-        let date = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
-        let result = find_next_day_of_month(&date, 31).unwrap();
-        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 3, 31).unwrap());
+/// Calculates the previous occurrence of a specific month and day before a given date.
+///
+/// This is the backward counterpart of [`find_next_annual_date`]. It first checks if the target
+/// date is in the past of the current year. If not, it searches for the most recent valid
+/// occurrence in the preceding years, which handles February 29.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `previous_month` - The target month (1-12).
+/// * `previous_day` - The target day (1-31).
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the previous matching date. Returns `None` if a valid
+/// date cannot be found within a reasonable number of past years (currently 8).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::find_previous_annual_date;
+///
+/// let date = NaiveDate::from_ymd_opt(2027, 3, 1).unwrap();
+/// let result = find_previous_annual_date(&date, 2, 29).unwrap();
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+/// ```
+pub fn find_previous_annual_date(
+    current_date: &NaiveDate,
+    previous_month: u32,
+    previous_day: u32,
+) -> Option<NaiveDate> {
+    let cur_year = current_date.year();
 
-        // Test case 6: Leap year, looking for the 29th from Feb 1st.
-        let date = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
-        let result = find_next_day_of_month(&date, 29).unwrap();
-        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+    // Try the date with the current year and see if it's applicable.
+    if let Some(date) = NaiveDate::from_ymd_opt(cur_year, previous_month, previous_day) {
+        if date.lt(current_date) {
+            return Some(date);
+        }
+    }
 
-        // Test case 7: Non-leap year, looking for the 29th from Feb 1st.
-        let date = NaiveDate::from_ymd_opt(2023, 2, 1).unwrap();
-        let result = find_next_day_of_month(&date, 29).unwrap();
-        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 3, 29).unwrap());
+    // Loop through the previous few years to find a valid date.
+    for i in 1..=8 {
+        if let Some(date) = NaiveDate::from_ymd_opt(cur_year - i, previous_month, previous_day) {
+            return Some(date);
+        }
     }
 
-    #[test]
-    fn test_find_next_annual_date() {
-        // Test case 1: Target date is in the same year and after the current date
-        let date = NaiveDate::from_ymd_opt(2023, 5, 15).unwrap();
-        let result = find_next_annual_date(&date, 6, 20).unwrap();
-        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 6, 20).unwrap());
+    None
+}
 
-        // Test case 2: Target date is in the same month and day as the current date, so it returns the next year's date
-        let date = NaiveDate::from_ymd_opt(2023, 5, 15).unwrap();
-        let result = find_next_annual_date(&date, 5, 15).unwrap();
-        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 5, 15).unwrap());
+/// Finds the nth occurrence of a weekday within a calendar year.
+///
+/// For example, `n = 10` with `Weekday::Mon` returns the 10th Monday of the year, counting the
+/// first Monday on or after January 1 as the 1st.
+///
+/// # Arguments
+///
+/// * `year` - The calendar year.
+/// * `weekday` - The target weekday.
+/// * `n` - The 1-based occurrence of the weekday within the year.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the nth matching date. Returns `None` if `n` is 0, if the
+/// year has fewer than `n` occurrences of the weekday, or if the year is out of range.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_nth_weekday_of_year;
+///
+/// let result = find_nth_weekday_of_year(2023, Weekday::Mon, 10).unwrap();
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 3, 6).unwrap());
+/// ```
+pub fn find_nth_weekday_of_year(year: i32, weekday: Weekday, n: u32) -> Option<NaiveDate> {
+    if n == 0 {
+        return None;
+    }
 
-        // Test case 3: Target date is in the same month but earlier in the month, so it returns the next year's date
-        let date = NaiveDate::from_ymd_opt(2023, 5, 15).unwrap();
-        let result = find_next_annual_date(&date, 5, 13).unwrap();
+    let year_start = NaiveDate::from_ymd_opt(year, 1, 1)?;
+    let offset = weekday.days_since(year_start.weekday()) as u64;
+    let date = year_start.checked_add_days(Days::new(offset + 7 * (n as u64 - 1)))?;
+
+    if date.year() == year {
+        Some(date)
+    } else {
+        None
+    }
+}
+
+/// Calculates the next date that falls on a specific weekday, along with whether a month or
+/// year boundary was crossed to reach it.
+///
+/// This behaves like [`find_next_weekday`], and additionally reports whether the result is in a
+/// different calendar month or year than `current_date`, which is handy for "next month" badges.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `weekday` - The target weekday.
+///
+/// # Returns
+///
+/// An `Option<(NaiveDate, bool, bool)>` containing the next date with the given weekday, whether
+/// it is in a different month, and whether it is in a different year. A different year always
+/// implies a different month. Returns `None` if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_next_weekday_crossing;
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 12, 28).unwrap(); // A Thursday
+/// let (date, new_month, new_year) =
+///     find_next_weekday_crossing(&current_date, Weekday::Wed).unwrap();
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 1, 3).unwrap());
+/// assert!(new_month);
+/// assert!(new_year);
+/// ```
+pub fn find_next_weekday_crossing(
+    current_date: &NaiveDate,
+    weekday: Weekday,
+) -> Option<(NaiveDate, bool, bool)> {
+    let date = find_next_weekday(current_date, weekday)?;
+    let new_year = date.year() != current_date.year();
+    let new_month = new_year || date.month() != current_date.month();
+    Some((date, new_month, new_year))
+}
+
+/// Finds the next date with a specific day of the month, moved off weekends by a [`WeekendRule`].
+///
+/// The target day is first resolved exactly like [`find_next_day_of_month`]: months without the
+/// day are skipped rather than clamped to their last day, so day 31 never resolves into
+/// February. The weekend adjustment is then applied to that date, which means the result may
+/// fall outside the target month (e.g. a Saturday the 31st followed to the next Monday).
+///
+/// As with every finder in this crate the result is strictly after `current_date`. If the
+/// [`WeekendRule::Preceding`] adjustment of the next target day would land on or before
+/// `current_date`, the following month's target day is used instead.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `day` - The target day of the month (1-31).
+/// * `weekend_rule` - How to move a target day that falls on a weekend.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next adjusted date. Returns `None` if the day is
+/// invalid or if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::{find_next_day_of_month_adjusted, WeekendRule};
+///
+/// // October 1st, 2023 is a Sunday.
+/// let current_date = NaiveDate::from_ymd_opt(2023, 9, 15).unwrap();
+/// let result = find_next_day_of_month_adjusted(&current_date, 1, WeekendRule::Following);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 2));
+/// ```
+pub fn find_next_day_of_month_adjusted(
+    current_date: &NaiveDate,
+    day: u32,
+    weekend_rule: WeekendRule,
+) -> Option<NaiveDate> {
+    let mut target = find_next_day_of_month(current_date, day)?;
+    loop {
+        let adjusted = weekend_rule.adjust(&target)?;
+        if adjusted > *current_date {
+            return Some(adjusted);
+        }
+        target = find_next_day_of_month(&target, day)?;
+    }
+}
+
+/// Finds a weekday within the nth week of a term.
+///
+/// Weeks begin on `week_start`, and week 1 is the week containing `term_start`. The result is the
+/// date of `weekday` within week `week`, counting the days of each week from `week_start`. For
+/// week 1 this can be a date before `term_start` if the term starts mid-week.
+///
+/// # Arguments
+///
+/// * `term_start` - The first day of the term.
+/// * `week` - The 1-based week number within the term.
+/// * `weekday` - The target weekday within that week.
+/// * `week_start` - The weekday each week begins on.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the matching date. Returns `None` if `week` is 0 or if the
+/// calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_weekday_of_term_week;
+///
+/// // A term starting on Wednesday, September 6th, with weeks beginning on Monday.
+/// let term_start = NaiveDate::from_ymd_opt(2023, 9, 6).unwrap();
+/// let result = find_weekday_of_term_week(&term_start, 2, Weekday::Mon, Weekday::Mon).unwrap();
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 9, 11).unwrap());
+/// ```
+pub fn find_weekday_of_term_week(
+    term_start: &NaiveDate,
+    week: u32,
+    weekday: Weekday,
+    week_start: Weekday,
+) -> Option<NaiveDate> {
+    if week == 0 {
+        return None;
+    }
+
+    let first_week_start = term_start.checked_sub_days(Days::new(
+        term_start.weekday().days_since(week_start).into(),
+    ))?;
+    let offset = 7 * (week as u64 - 1) + weekday.days_since(week_start) as u64;
+    first_week_start.checked_add_days(Days::new(offset))
+}
+
+/// Finds the nth business day of a month.
+///
+/// Business days are counted from the 1st of the month, skipping weekends and the holidays
+/// reported by `calendar` (see [`is_business_day`]).
+///
+/// # Arguments
+///
+/// * `year` - The calendar year.
+/// * `month` - The month (1-12).
+/// * `n` - The 1-based business day to find.
+/// * `calendar` - The holidays to skip in addition to weekends.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the nth business day. Returns `None` if `n` is 0, if the
+/// month is invalid, or if the month has fewer than `n` business days.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::{find_nth_business_day_of_month, NoHolidays};
+///
+/// // October 1st, 2023 is a Sunday, so the 3rd business day is Wednesday the 4th.
+/// let result = find_nth_business_day_of_month(2023, 10, 3, &NoHolidays);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 4));
+/// ```
+pub fn find_nth_business_day_of_month(
+    year: i32,
+    month: u32,
+    n: u32,
+    calendar: &impl HolidayCalendar,
+) -> Option<NaiveDate> {
+    if n == 0 {
+        return None;
+    }
+
+    let month_start = NaiveDate::from_ymd_opt(year, month, 1)?;
+    month_start
+        .iter_days()
+        .take_while(|date| date.month() == month)
+        .filter(|date| is_business_day(date, calendar))
+        .nth(n as usize - 1)
+}
+
+/// Returns the last day of the given month, or `None` if the month is invalid.
+fn last_day_of_month(year: i32, month: u32) -> Option<NaiveDate> {
+    // December is handled directly so that it works in the last representable year.
+    if month == 12 {
+        return NaiveDate::from_ymd_opt(year, 12, 31);
+    }
+    NaiveDate::from_ymd_opt(year, month, 1)?
+        .checked_add_months(Months::new(1))?
+        .pred_opt()
+}
+
+/// Returns the number of days in a month.
+///
+/// Leap years follow the proleptic Gregorian calendar, so February has 29 days in years divisible
+/// by 4, except for years divisible by 100 but not by 400.
+///
+/// # Arguments
+///
+/// * `year` - The calendar year.
+/// * `month` - The month (1-12).
+///
+/// # Returns
+///
+/// An `Option<u32>` containing the number of days, between 28 and 31. Returns `None` if the
+/// month is invalid or the year is out of range.
+///
+/// # Examples
+///
+/// ```
+/// use next_matching_day::days_in_month;
+///
+/// assert_eq!(days_in_month(2024, 2), Some(29));
+/// assert_eq!(days_in_month(2023, 2), Some(28));
+/// assert_eq!(days_in_month(2023, 13), None);
+/// ```
+pub fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    last_day_of_month(year, month).map(|date| date.day())
+}
+
+/// Finds the last occurrence of a weekday within a month.
+///
+/// # Arguments
+///
+/// * `year` - The calendar year.
+/// * `month` - The month (1-12).
+/// * `weekday` - The target weekday.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the last matching date. Returns `None` if the month is
+/// invalid.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_last_weekday_of_month;
+///
+/// // Memorial Day 2024: the last Monday of May.
+/// let result = find_last_weekday_of_month(2024, 5, Weekday::Mon);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 5, 27));
+/// ```
+pub fn find_last_weekday_of_month(year: i32, month: u32, weekday: Weekday) -> Option<NaiveDate> {
+    let month_end = last_day_of_month(year, month)?;
+    let days_since = month_end.weekday().days_since(weekday);
+    month_end.checked_sub_days(Days::new(days_since.into()))
+}
+
+/// Returns `true` if `date` is the last occurrence of its weekday in its month.
+///
+/// This is the case when no later date in the same month falls on the same weekday, i.e. when
+/// the date one week later is in a different month.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::is_last_weekday_of_month;
+///
+/// // October 27th, 2023 is the last Friday of October.
+/// assert!(is_last_weekday_of_month(&NaiveDate::from_ymd_opt(2023, 10, 27).unwrap()));
+/// assert!(!is_last_weekday_of_month(&NaiveDate::from_ymd_opt(2023, 10, 20).unwrap()));
+/// ```
+pub fn is_last_weekday_of_month(date: &NaiveDate) -> bool {
+    date.checked_add_days(Days::new(7))
+        .is_none_or(|next_week| next_week.month() != date.month())
+}
+
+/// Finds the nth occurrence of a weekday within a month.
+///
+/// # Arguments
+///
+/// * `year` - The calendar year.
+/// * `month` - The month (1-12).
+/// * `weekday` - The target weekday.
+/// * `n` - The 1-based occurrence of the weekday within the month.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the nth matching date. Returns `None` if `n` is 0, if the
+/// month is invalid, or if the month has fewer than `n` occurrences of the weekday.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_nth_weekday_of_month;
+///
+/// // Thanksgiving 2023: the 4th Thursday of November.
+/// let result = find_nth_weekday_of_month(2023, 11, Weekday::Thu, 4);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 23));
+/// ```
+pub fn find_nth_weekday_of_month(
+    year: i32,
+    month: u32,
+    weekday: Weekday,
+    n: u32,
+) -> Option<NaiveDate> {
+    if n == 0 {
+        return None;
+    }
+
+    let month_start = NaiveDate::from_ymd_opt(year, month, 1)?;
+    let offset = weekday.days_since(month_start.weekday()) as u64;
+    let date = month_start.checked_add_days(Days::new(offset + 7 * (n as u64 - 1)))?;
+
+    if date.month() == month {
+        Some(date)
+    } else {
+        None
+    }
+}
+
+/// Calculates the next occurrence of the nth weekday of a given month, such as "the 2nd Sunday
+/// of May".
+///
+/// If this year's occurrence is still upcoming it is returned, otherwise the search continues in
+/// the following years. Years where the month has fewer than `n` occurrences of the weekday are
+/// skipped.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `month` - The target month (1-12).
+/// * `weekday` - The target weekday.
+/// * `n` - The 1-based occurrence of the weekday within the month.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next matching date strictly after `current_date`.
+/// Returns `None` if `n` or `month` is invalid, or if the month never has an nth occurrence of
+/// the weekday. A 5th occurrence can be decades apart, so the search covers a whole 400-year
+/// Gregorian cycle, after which the calendar repeats.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_next_annual_nth_weekday;
+///
+/// // Mother's Day: the 2nd Sunday of May.
+/// let current_date = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+/// let result = find_next_annual_nth_weekday(&current_date, 5, Weekday::Sun, 2);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2025, 5, 11));
+/// ```
+pub fn find_next_annual_nth_weekday(
+    current_date: &NaiveDate,
+    month: u32,
+    weekday: Weekday,
+    n: u32,
+) -> Option<NaiveDate> {
+    const GREGORIAN_CYCLE_YEARS: i32 = 400;
+    let cur_year = current_date.year();
+
+    for i in 0..=GREGORIAN_CYCLE_YEARS {
+        if let Some(date) = find_nth_weekday_of_month(cur_year + i, month, weekday, n) {
+            if date.gt(current_date) {
+                return Some(date);
+            }
+        }
+    }
+
+    None
+}
+
+/// Calculates the next occurrence of the last weekday of a given month, such as "the last
+/// Monday of May".
+///
+/// If this year's occurrence is still upcoming it is returned, otherwise the following year's
+/// occurrence is returned.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `month` - The target month (1-12).
+/// * `weekday` - The target weekday.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next matching date strictly after `current_date`.
+/// Returns `None` if the month is invalid or if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_next_annual_last_weekday;
+///
+/// // Memorial Day: the last Monday of May.
+/// let current_date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// let result = find_next_annual_last_weekday(&current_date, 5, Weekday::Mon);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 5, 27));
+/// ```
+pub fn find_next_annual_last_weekday(
+    current_date: &NaiveDate,
+    month: u32,
+    weekday: Weekday,
+) -> Option<NaiveDate> {
+    let cur_year = current_date.year();
+
+    let date = find_last_weekday_of_month(cur_year, month, weekday)?;
+    if date.gt(current_date) {
+        return Some(date);
+    }
+
+    find_last_weekday_of_month(cur_year + 1, month, weekday)
+}
+
+/// Calculates the next anniversary of `origin` and how many years it represents.
+///
+/// The anniversary is found with [`find_next_annual_date`] using the month and day of `origin`,
+/// so an origin on February 29 only has anniversaries in leap years. The origin year itself is
+/// never counted: if `current_date` is before `origin`, the first anniversary after the origin
+/// is returned.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `origin` - The date of the original event.
+///
+/// # Returns
+///
+/// An `Option<(NaiveDate, i32)>` containing the next anniversary strictly after `current_date`
+/// and the number of years between `origin` and that anniversary. Returns `None` if the
+/// calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::find_next_annual_date_with_year_count;
+///
+/// let origin = NaiveDate::from_ymd_opt(2015, 6, 20).unwrap();
+/// let current_date = NaiveDate::from_ymd_opt(2023, 7, 1).unwrap();
+/// let result = find_next_annual_date_with_year_count(&current_date, &origin);
+/// assert_eq!(result, Some((NaiveDate::from_ymd_opt(2024, 6, 20).unwrap(), 9)));
+/// ```
+pub fn find_next_annual_date_with_year_count(
+    current_date: &NaiveDate,
+    origin: &NaiveDate,
+) -> Option<(NaiveDate, i32)> {
+    let from = current_date.max(origin);
+    let date = find_next_annual_date(from, origin.month(), origin.day())?;
+    Some((date, date.year() - origin.year()))
+}
+
+/// Calculates the next date that falls on a specific weekday, saturating at the end of the
+/// representable range instead of returning `None`.
+///
+/// Away from the limits of `NaiveDate` this returns exactly what [`find_next_weekday`] returns.
+/// When the next matching date would be after [`NaiveDate::MAX`], it returns the latest
+/// representable date falling on `weekday` instead. In that case the result is on or before
+/// `current_date`, so callers looping on the result should stop once it no longer advances.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `weekday` - The target weekday.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, NaiveDate, Weekday};
+/// use next_matching_day::find_next_weekday_saturating;
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // A Sunday
+/// let result = find_next_weekday_saturating(&current_date, Weekday::Mon);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 16).unwrap());
+///
+/// let result = find_next_weekday_saturating(&NaiveDate::MAX, Weekday::Mon);
+/// assert!(result <= NaiveDate::MAX);
+/// assert_eq!(result.weekday(), Weekday::Mon);
+/// ```
+pub fn find_next_weekday_saturating(current_date: &NaiveDate, weekday: Weekday) -> NaiveDate {
+    find_next_weekday(current_date, weekday).unwrap_or_else(|| {
+        let days_since = NaiveDate::MAX.weekday().days_since(weekday);
+        NaiveDate::MAX - Days::new(days_since.into())
+    })
+}
+
+/// Finds the next date on an "every `n` days" grid starting at `anchor`.
+///
+/// The grid consists of the dates `anchor + k * n` for `k = 0, 1, 2, ...`. The result is the
+/// first grid date strictly after `current_date`, so if `current_date` is before `anchor` the
+/// anchor itself is returned.
+///
+/// # Arguments
+///
+/// * `anchor` - The first date of the grid.
+/// * `current_date` - The starting date.
+/// * `n` - The number of days between grid dates.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next grid date. Returns `None` if `n` is 0 or if the
+/// calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::find_next_every_n_days;
+///
+/// let anchor = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+/// let current_date = NaiveDate::from_ymd_opt(2023, 1, 15).unwrap();
+/// let result = find_next_every_n_days(&anchor, &current_date, 10);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 1, 21));
+/// ```
+pub fn find_next_every_n_days(
+    anchor: &NaiveDate,
+    current_date: &NaiveDate,
+    n: u32,
+) -> Option<NaiveDate> {
+    if n == 0 {
+        return None;
+    }
+    if current_date < anchor {
+        return Some(*anchor);
+    }
+
+    let days_since_anchor = (*current_date - *anchor).num_days() as u64;
+    let steps = days_since_anchor / n as u64 + 1;
+    anchor.checked_add_days(Days::new(steps * n as u64))
+}
+
+/// Finds the first date with a specific day of the month, starting from a year and month
+/// rather than a full date.
+///
+/// Unlike [`find_next_day_of_month`], the search is inclusive: the 1st of the given month is
+/// itself a candidate, so this returns the day within `month` if that month has it. Months
+/// without the day are skipped, so day 31 from February resolves to March 31st.
+///
+/// # Arguments
+///
+/// * `year` - The year to start searching from.
+/// * `month` - The month (1-12) to start searching from.
+/// * `day` - The target day of the month (1-31).
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the first matching date on or after the 1st of the given
+/// month. Returns `None` if the month or day is invalid or if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::find_next_day_of_month_from_ym;
+///
+/// let result = find_next_day_of_month_from_ym(2023, 10, 15);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 15));
+///
+/// // February never has a 31st, so the search moves on to March.
+/// let result = find_next_day_of_month_from_ym(2023, 2, 31);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 3, 31));
+/// ```
+pub fn find_next_day_of_month_from_ym(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+    if day == 1 {
+        return Some(first);
+    }
+    find_next_day_of_month(&first, day)
+}
+
+/// Finds the date a number of business days after the next occurrence of a day of the month.
+///
+/// This composes [`find_next_day_of_month`] with [`add_business_days`], for settlement rules
+/// such as "two business days after the 1st". The anchor is the next occurrence of `day`
+/// strictly after `current_date`, and the offset is counted in business days after the anchor,
+/// so an offset of 0 returns the anchor itself even if it is not a business day.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `day` - The anchor day of the month (1-31).
+/// * `offset` - The number of business days after the anchor.
+/// * `calendar` - The holidays to skip in addition to weekends.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the offset date. Returns `None` if the day is invalid or
+/// if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::{find_next_day_of_month_plus_business_days, NoHolidays};
+///
+/// // December 1st, 2023 is a Friday, so T+2 is Tuesday the 5th.
+/// let current_date = NaiveDate::from_ymd_opt(2023, 11, 15).unwrap();
+/// let result = find_next_day_of_month_plus_business_days(&current_date, 1, 2, &NoHolidays);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 12, 5));
+/// ```
+pub fn find_next_day_of_month_plus_business_days(
+    current_date: &NaiveDate,
+    day: u32,
+    offset: u32,
+    calendar: &impl HolidayCalendar,
+) -> Option<NaiveDate> {
+    let anchor = find_next_day_of_month(current_date, day)?;
+    add_business_days(&anchor, offset, calendar)
+}
+
+/// Finds the next date whose weekday falls within a contiguous range of weekdays.
+///
+/// The range runs from `first` to `last` inclusive, following the order of the week. It may
+/// wrap around the end of the week, so `Fri..=Mon` covers Friday, Saturday, Sunday and Monday.
+/// If `first` and `last` are the same weekday, the range contains only that weekday.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `first` - The first weekday of the range.
+/// * `last` - The last weekday of the range.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next date strictly after `current_date` whose weekday
+/// is in the range. Returns `None` if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_next_weekday_in_set_contiguous;
+///
+/// // From Thursday, the next day in Mon..=Thu is the following Monday.
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 19).unwrap();
+/// let result = find_next_weekday_in_set_contiguous(&current_date, Weekday::Mon, Weekday::Thu);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 23));
+/// ```
+pub fn find_next_weekday_in_set_contiguous(
+    current_date: &NaiveDate,
+    first: Weekday,
+    last: Weekday,
+) -> Option<NaiveDate> {
+    let range_len = last.days_since(first);
+    let mut date = *current_date;
+    loop {
+        date = date.succ_opt()?;
+        if date.weekday().days_since(first) <= range_len {
+            return Some(date);
+        }
+    }
+}
+
+/// Finds the next date with a specific day of the month, reporting why no date was found.
+///
+/// This is the fallible counterpart of [`find_next_day_of_month`], which returns `None` both
+/// for a day that can never occur and for an exhausted search. Here the two cases are told
+/// apart, so that callers can decide whether widening the search would help. Every day from 1
+/// to 31 occurs at least once in any 12-month window, so valid days only fail near the end of
+/// the representable date range.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `next_day` - The target day of the month (1-31).
+///
+/// # Errors
+///
+/// * [`NextDayError::InvalidDay`] if `next_day` is not between 1 and 31.
+/// * [`NextDayError::NotFoundWithinWindow`] if no matching date exists within the next 12
+///   months, which only happens when the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::{try_find_next_day_of_month, NextDayError};
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+/// assert_eq!(
+///     try_find_next_day_of_month(&current_date, 31),
+///     Ok(NaiveDate::from_ymd_opt(2023, 3, 31).unwrap())
+/// );
+/// assert_eq!(
+///     try_find_next_day_of_month(&current_date, 32),
+///     Err(NextDayError::InvalidDay(32))
+/// );
+/// ```
+pub fn try_find_next_day_of_month(
+    current_date: &NaiveDate,
+    next_day: u32,
+) -> Result<NaiveDate, NextDayError> {
+    if !(1..=31).contains(&next_day) {
+        return Err(NextDayError::InvalidDay(next_day));
+    }
+    find_next_day_of_month(current_date, next_day).ok_or(NextDayError::NotFoundWithinWindow)
+}
+
+/// Returns the start of the quarter containing `date`, with quarters beginning on the 1st of
+/// `fiscal_start_month` and every third month after it.
+fn quarter_start_on_or_before(date: &NaiveDate, fiscal_start_month: u32) -> Option<NaiveDate> {
+    if !(1..=12).contains(&fiscal_start_month) {
+        return None;
+    }
+    let months_into_quarter = (date.month0() + 12 - (fiscal_start_month - 1)) % 3;
+    date.with_day(1)?
+        .checked_sub_months(Months::new(months_into_quarter))
+}
+
+/// Finds the start of the next quarter.
+///
+/// Quarters begin on the 1st of `fiscal_start_month` and of every third month after it, so a
+/// fiscal year starting in April has quarters starting in April, July, October and January.
+/// Use a `fiscal_start_month` of 1 for calendar quarters.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `fiscal_start_month` - The month (1-12) the fiscal year starts in.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next quarter start strictly after `current_date`.
+/// Returns `None` if the month is invalid or if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::find_next_quarter_start;
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
+/// let result = find_next_quarter_start(&current_date, 1);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 1, 1));
+///
+/// let result = find_next_quarter_start(&current_date, 2);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 1));
+/// ```
+pub fn find_next_quarter_start(
+    current_date: &NaiveDate,
+    fiscal_start_month: u32,
+) -> Option<NaiveDate> {
+    quarter_start_on_or_before(current_date, fiscal_start_month)?.checked_add_months(Months::new(3))
+}
+
+/// Snaps a date to the closest quarter start, either before or after it.
+///
+/// Quarters are defined as in [`find_next_quarter_start`]. A date that is itself a quarter start
+/// is returned unchanged. When the date is exactly as far from the previous quarter start as
+/// from the next one, the previous quarter start is returned.
+///
+/// # Arguments
+///
+/// * `date` - The date to snap.
+/// * `fiscal_start_month` - The month (1-12) the fiscal year starts in.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the closest quarter start. Returns `None` if the month is
+/// invalid.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::nearest_quarter_boundary;
+///
+/// let date = NaiveDate::from_ymd_opt(2023, 5, 20).unwrap();
+/// let result = nearest_quarter_boundary(&date, 1);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 7, 1));
+/// ```
+pub fn nearest_quarter_boundary(date: &NaiveDate, fiscal_start_month: u32) -> Option<NaiveDate> {
+    if !(1..=12).contains(&fiscal_start_month) {
+        return None;
+    }
+    let Some(previous) = quarter_start_on_or_before(date, fiscal_start_month) else {
+        // The previous quarter start can only be missing in the first months of the
+        // representable range, where the next one is the only candidate.
+        let later = date.checked_add_months(Months::new(3))?;
+        return quarter_start_on_or_before(&later, fiscal_start_month);
+    };
+    match previous.checked_add_months(Months::new(3)) {
+        Some(next) if next - *date < *date - previous => Some(next),
+        _ => Some(previous),
+    }
+}
+
+/// Finds the next Friday the 13th.
+///
+/// The search goes month by month. Every calendar year has at least one Friday the 13th and
+/// consecutive ones are never more than 14 months apart, so the search covers the current
+/// month and the 14 months after it.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next Friday the 13th strictly after `current_date`.
+/// Returns `None` only if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::find_next_friday_the_13th;
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
+/// let result = find_next_friday_the_13th(&current_date);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 9, 13));
+/// ```
+pub fn find_next_friday_the_13th(current_date: &NaiveDate) -> Option<NaiveDate> {
+    let month_start = current_date.with_day(1)?;
+    (0..=14)
+        .filter_map(|i| month_start.checked_add_months(Months::new(i))?.with_day(13))
+        .find(|date| date > current_date && date.weekday() == Weekday::Fri)
+}
+
+/// Finds the next date matching an arbitrary predicate, checking at most `max_days` days.
+///
+/// The days after `current_date` are tested one at a time, so the bound keeps a predicate that
+/// rarely or never matches from scanning indefinitely.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `max_days` - The maximum number of days after `current_date` to check.
+/// * `predicate` - Returns `true` for a matching date.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the first matching date strictly after `current_date` and
+/// at most `max_days` days after it. Returns `None` if no date within the bound matches or if
+/// the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, NaiveDate, Weekday};
+/// use next_matching_day::find_next_matching;
+///
+/// // The next weekend day that is also the 1st of a month.
+/// let current_date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+/// let result = find_next_matching(&current_date, 366, |date| {
+///     date.day() == 1 && matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+/// });
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 4, 1));
+/// ```
+pub fn find_next_matching(
+    current_date: &NaiveDate,
+    max_days: u32,
+    mut predicate: impl FnMut(&NaiveDate) -> bool,
+) -> Option<NaiveDate> {
+    let mut date = *current_date;
+    for _ in 0..max_days {
+        date = date.succ_opt()?;
+        if predicate(&date) {
+            return Some(date);
+        }
+    }
+    None
+}
+
+/// Finds the next date matching an arbitrary predicate, without choosing a bound.
+///
+/// This is [`find_next_matching`] with a fixed bound of 146,097 days, the length of the 400-year
+/// cycle after which the Gregorian calendar repeats exactly. A predicate that only depends on
+/// the month, day, weekday and leap years therefore matches within the bound if it ever
+/// matches, and one that never matches still returns `None` after a finite scan.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, NaiveDate, Weekday};
+/// use next_matching_day::find_next_matching_unbounded;
+///
+/// // The next February 29th that falls on a Monday.
+/// let current_date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+/// let result = find_next_matching_unbounded(&current_date, |date| {
+///     date.month() == 2 && date.day() == 29 && date.weekday() == Weekday::Mon
+/// });
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2044, 2, 29));
+/// ```
+pub fn find_next_matching_unbounded(
+    current_date: &NaiveDate,
+    predicate: impl FnMut(&NaiveDate) -> bool,
+) -> Option<NaiveDate> {
+    const GREGORIAN_CYCLE_DAYS: u32 = 146_097;
+
+    find_next_matching(current_date, GREGORIAN_CYCLE_DAYS, predicate)
+}
+
+/// Finds the next date a fixed number of days before the end of a month.
+///
+/// With `days_before = 0` this is the last day of the month, and with `days_before = 3` it is
+/// the 28th of a 31-day month but the 25th of a non-leap February. Months too short to contain
+/// such a date (for example `days_before = 29` in February) are skipped.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `days_before` - The number of days before the last day of the month.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next matching date strictly after `current_date`.
+/// Returns `None` if `days_before` is 31 or more, or if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::find_next_days_before_month_end;
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 2, 1).unwrap();
+/// let result = find_next_days_before_month_end(&current_date, 3);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 2, 25));
+/// ```
+pub fn find_next_days_before_month_end(
+    current_date: &NaiveDate,
+    days_before: u32,
+) -> Option<NaiveDate> {
+    let month_start = current_date.with_day(1)?;
+    for i in 0..=12 {
+        let month = month_start.checked_add_months(Months::new(i))?;
+        let month_end = last_day_of_month(month.year(), month.month())?;
+        if days_before >= month_end.day() {
+            continue;
+        }
+        let date = month_end - Days::new(days_before.into());
+        if date > *current_date {
+            return Some(date);
+        }
+    }
+
+    None
+}
+
+/// Returns `true` if two dates fall in the same ISO 8601 week.
+///
+/// ISO weeks start on Monday and belong to the ISO year containing their Thursday, so the days
+/// around New Year can share a week with dates of the adjacent calendar year. Both the week
+/// number and the ISO year are compared, rather than the calendar year.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::same_iso_week;
+///
+/// // Monday, December 30th, 2024 is in the first ISO week of 2025.
+/// let a = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+/// let b = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+/// assert!(same_iso_week(&a, &b));
+/// ```
+pub fn same_iso_week(a: &NaiveDate, b: &NaiveDate) -> bool {
+    a.iso_week() == b.iso_week()
+}
+
+/// Finds the nth occurrence of a weekday after a date.
+///
+/// The 1st occurrence is [`find_next_weekday`], and each following one is a week later, so a
+/// date that already falls on `weekday` is never counted.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `weekday` - The target weekday.
+/// * `n` - The 1-based occurrence to find.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the nth matching date strictly after `current_date`.
+/// Returns `None` if `n` is 0 or if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_nth_next_weekday;
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // A Sunday
+/// let result = find_nth_next_weekday(&current_date, Weekday::Mon, 3);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 30));
+/// ```
+pub fn find_nth_next_weekday(
+    current_date: &NaiveDate,
+    weekday: Weekday,
+    n: u32,
+) -> Option<NaiveDate> {
+    if n == 0 {
+        return None;
+    }
+    let days_distance =
+        days_forward_to(current_date.weekday(), weekday) as u64 + 7 * (n as u64 - 1);
+    current_date.checked_add_days(Days::new(days_distance))
+}
+
+/// Finds a weekday in the week that is a number of weeks after the current one.
+///
+/// Weeks run from Monday to Sunday, as in ISO 8601. The result is the date of `weekday` within
+/// the week `weeks` weeks after the one containing `current_date`, so `weeks = 0` gives the
+/// date of `weekday` in the current week, which may be on or before `current_date`.
+///
+/// This differs from [`find_nth_next_weekday`], which counts occurrences rather than weeks:
+/// from a Monday, the Wednesday "in 2 weeks" is 16 days later, while the 2nd next Wednesday is
+/// only 9 days later. The two agree when `weekday` is not later in the week than
+/// `current_date`.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `weekday` - The target weekday.
+/// * `weeks` - The number of weeks ahead of the current week.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the matching date. Returns `None` if the calculation
+/// overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_weekday_in_n_weeks;
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(); // A Monday
+/// let result = find_weekday_in_n_weeks(&current_date, Weekday::Wed, 2);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 1));
+/// ```
+pub fn find_weekday_in_n_weeks(
+    current_date: &NaiveDate,
+    weekday: Weekday,
+    weeks: u32,
+) -> Option<NaiveDate> {
+    find_weekday_of_term_week(current_date, weeks.checked_add(1)?, weekday, Weekday::Mon)
+}
+
+/// Calculates the next `N` dates that fall on a specific weekday, without allocating.
+///
+/// The first date is [`find_next_weekday`] and each following one is a week later, so the
+/// result is the same as taking `N` items from [`weekday_iter`] but as a stack array.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `weekday` - The target weekday.
+///
+/// # Returns
+///
+/// An `Option<[NaiveDate; N]>` containing the next `N` matching dates in order. Returns `None`
+/// if any of them overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_next_weekdays_const;
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // A Sunday
+/// let [first, second] = find_next_weekdays_const(&current_date, Weekday::Mon).unwrap();
+/// assert_eq!(first, NaiveDate::from_ymd_opt(2023, 10, 16).unwrap());
+/// assert_eq!(second, NaiveDate::from_ymd_opt(2023, 10, 23).unwrap());
+/// ```
+pub fn find_next_weekdays_const<const N: usize>(
+    current_date: &NaiveDate,
+    weekday: Weekday,
+) -> Option<[NaiveDate; N]> {
+    let mut date = find_next_weekday(current_date, weekday)?;
+    let mut dates = [date; N];
+    for slot in dates.iter_mut().skip(1) {
+        date = date.checked_add_days(Days::new(7))?;
+        *slot = date;
+    }
+    Some(dates)
+}
+
+/// Computes whether two biweekly schedules are in phase.
+///
+/// Each anchor is reduced to the Monday-to-Sunday week containing it, and the result is the
+/// number of weeks between those weeks modulo 2. Anchors in the same week, or an even number of
+/// weeks apart, are in phase and give 0. Anchors an odd number of weeks apart give 1. The
+/// weekday of each anchor within its week does not matter.
+///
+/// # Arguments
+///
+/// * `anchor_a` - A date in an on-week of the first schedule.
+/// * `anchor_b` - A date in an on-week of the second schedule.
+///
+/// # Returns
+///
+/// 0 if the schedules share their on-weeks, 1 if they alternate.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::biweekly_phase_offset;
+///
+/// let a = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(); // A Monday
+/// let b = NaiveDate::from_ymd_opt(2023, 10, 26).unwrap(); // The Thursday of the next week
+/// assert_eq!(biweekly_phase_offset(&a, &b), 1);
+/// ```
+pub fn biweekly_phase_offset(anchor_a: &NaiveDate, anchor_b: &NaiveDate) -> i64 {
+    let week_start = |date: &NaiveDate| {
+        date.num_days_from_ce() as i64 - date.weekday().num_days_from_monday() as i64
+    };
+    ((week_start(anchor_b) - week_start(anchor_a)) / 7).rem_euclid(2)
+}
+
+/// Finds the next date with a specific day of the month that does not fall on certain weekdays.
+///
+/// Occurrences of the day are found as in [`find_next_day_of_month`], and any occurrence whose
+/// weekday is in `avoid` is skipped in favour of the following month's occurrence, as many
+/// times as needed. The date is never moved within its month.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `day` - The target day of the month (1-31).
+/// * `avoid` - The weekdays the result must not fall on.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next matching date. Returns `None` if the day is
+/// invalid, if `avoid` contains every weekday, or if the calculation overflows. The search
+/// covers 400 years, after which the Gregorian calendar repeats.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_next_day_of_month_avoiding_weekdays;
+///
+/// // October 15th, 2023 is a Sunday, so November 15th is used instead.
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 1).unwrap();
+/// let result = find_next_day_of_month_avoiding_weekdays(
+///     &current_date,
+///     15,
+///     &[Weekday::Sat, Weekday::Sun],
+/// );
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 15));
+/// ```
+pub fn find_next_day_of_month_avoiding_weekdays(
+    current_date: &NaiveDate,
+    day: u32,
+    avoid: &[Weekday],
+) -> Option<NaiveDate> {
+    let mut date = *current_date;
+    for _ in 0..12 * 400 {
+        date = find_next_day_of_month(&date, day)?;
+        if !avoid.contains(&date.weekday()) {
+            return Some(date);
+        }
+    }
+
+    None
+}
+
+/// Calculates the next date that falls on a specific weekday, unless it is after a limit.
+///
+/// This is [`find_next_weekday`] followed by a check against `clamp_max`, for queries such as
+/// "next Monday, but no later than the deadline". A result exactly on `clamp_max` is allowed.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `weekday` - The target weekday.
+/// * `clamp_max` - The latest acceptable date, or `None` for no limit.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next matching date. Returns `None` if that date is
+/// after `clamp_max` or if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_next_weekday_clamped;
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // A Sunday
+/// let deadline = NaiveDate::from_ymd_opt(2023, 10, 20).unwrap();
+/// assert_eq!(
+///     find_next_weekday_clamped(&current_date, Weekday::Mon, Some(deadline)),
+///     NaiveDate::from_ymd_opt(2023, 10, 16)
+/// );
+/// assert_eq!(
+///     find_next_weekday_clamped(&current_date, Weekday::Sat, Some(deadline)),
+///     None
+/// );
+/// ```
+pub fn find_next_weekday_clamped(
+    current_date: &NaiveDate,
+    weekday: Weekday,
+    clamp_max: Option<NaiveDate>,
+) -> Option<NaiveDate> {
+    find_next_weekday(current_date, weekday).filter(|date| clamp_max.is_none_or(|max| *date <= max))
+}
+
+/// Returns the date a fixed-date holiday is observed on, following the US federal rule.
+///
+/// A holiday on a weekday is observed on the day itself. One falling on a Saturday is observed
+/// on the preceding Friday, and one falling on a Sunday on the following Monday, i.e. the
+/// nearest weekday. This is [`WeekendRule::Preceding`] for Saturdays and
+/// [`WeekendRule::Following`] for Sundays.
+///
+/// # Arguments
+///
+/// * `year` - The calendar year.
+/// * `month` - The month (1-12) of the holiday.
+/// * `day` - The day (1-31) of the holiday.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the observed date. Returns `None` if the date does not
+/// exist or if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::observed_weekday_for_fixed_date;
+///
+/// // July 4th, 2026 is a Saturday, so it is observed on Friday, July 3rd.
+/// let result = observed_weekday_for_fixed_date(2026, 7, 4);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2026, 7, 3));
+/// ```
+pub fn observed_weekday_for_fixed_date(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    let date = NaiveDate::from_ymd_opt(year, month, day)?;
+    match date.weekday() {
+        Weekday::Sat => WeekendRule::Preceding.adjust(&date),
+        Weekday::Sun => WeekendRule::Following.adjust(&date),
+        _ => Some(date),
+    }
+}
+
+/// Computes the calendar difference between two dates as years, months and days.
+///
+/// The whole months from `from` are counted first, then the remaining days. When a month is
+/// added to a day that the target month does not have, it lands on that month's last day, as
+/// with [`NaiveDate::checked_add_months`]. So from January 31st to February 29th is exactly 1
+/// month, from January 31st to March 1st is 1 month (to February 28th) and 1 day in a non-leap
+/// year, and from February 29th to the next February 28th is 1 year.
+///
+/// # Arguments
+///
+/// * `from` - The earlier date.
+/// * `to` - The later date.
+///
+/// # Returns
+///
+/// An `Option<(i32, u32, u32)>` containing the years, the months (0-11) and the days. Returns
+/// `None` if `to` is before `from`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::elapsed_ymd;
+///
+/// let from = NaiveDate::from_ymd_opt(2020, 8, 10).unwrap();
+/// let to = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
+/// assert_eq!(elapsed_ymd(&from, &to), Some((3, 2, 5)));
+/// ```
+pub fn elapsed_ymd(from: &NaiveDate, to: &NaiveDate) -> Option<(i32, u32, u32)> {
+    if to < from {
+        return None;
+    }
+
+    let mut months = (to.year() - from.year()) * 12 + to.month() as i32 - from.month() as i32;
+    let mut anchor = from.checked_add_months(Months::new(months as u32))?;
+    if anchor > *to {
+        months -= 1;
+        anchor = from.checked_add_months(Months::new(months as u32))?;
+    }
+    let days = (*to - anchor).num_days() as u32;
+    Some((months / 12, (months % 12) as u32, days))
+}
+
+/// Finds the next date whose day of the month equals its month number, such as 5/5 or 12/12.
+///
+/// There are twelve such dates every year, from January 1st to December 12th, and every month
+/// has its day since no month number exceeds 28. After December 12th the next one is January
+/// 1st of the following year.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next matching date strictly after `current_date`.
+/// Returns `None` only if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::find_next_palindrome_style_nn;
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 5, 6).unwrap();
+/// let result = find_next_palindrome_style_nn(&current_date);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 6, 6));
+/// ```
+pub fn find_next_palindrome_style_nn(current_date: &NaiveDate) -> Option<NaiveDate> {
+    let year = current_date.year();
+    (current_date.month()..=12)
+        .filter_map(|month| NaiveDate::from_ymd_opt(year, month, month))
+        .find(|date| date > current_date)
+        .or_else(|| NaiveDate::from_ymd_opt(year.checked_add(1)?, 1, 1))
+}
+
+/// Lists the dates of a weekday within a range, keeping only every `week_step`th week.
+///
+/// The first date is the first `weekday` on or after `start`, and each following date is
+/// `week_step` weeks after the previous one, up to and including `end`. With `week_step = 1`
+/// this lists every `weekday` in the range.
+///
+/// # Arguments
+///
+/// * `start` - The first date of the range.
+/// * `end` - The last date of the range.
+/// * `weekday` - The target weekday.
+/// * `week_step` - The number of weeks between consecutive dates.
+///
+/// # Returns
+///
+/// A `Vec<NaiveDate>` of the matching dates in ascending order. It is empty if `week_step` is
+/// 0, if `end` is before `start`, or if the range contains no `weekday`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::stepped_weekdays_in_range;
+///
+/// // Every third Tuesday from October 1st to the end of the year.
+/// let start = NaiveDate::from_ymd_opt(2023, 10, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+/// let dates = stepped_weekdays_in_range(&start, &end, Weekday::Tue, 3);
+/// assert_eq!(dates.len(), 5);
+/// assert_eq!(dates[0], NaiveDate::from_ymd_opt(2023, 10, 3).unwrap());
+/// ```
+pub fn stepped_weekdays_in_range(
+    start: &NaiveDate,
+    end: &NaiveDate,
+    weekday: Weekday,
+    week_step: u32,
+) -> Vec<NaiveDate> {
+    if week_step == 0 {
+        return Vec::new();
+    }
+
+    let step = Days::new(7 * week_step as u64);
+    let first = start.checked_add_days(Days::new(weekday.days_since(start.weekday()).into()));
+    std::iter::successors(first, |date| date.checked_add_days(step))
+        .take_while(|date| date <= end)
+        .collect()
+}
+
+/// Finds the next date that falls on a weekday in a week with the requested parity.
+///
+/// Weeks run from Monday to Sunday and are counted from the week containing `reference`, which
+/// is week 0 and therefore even. The result is the next `weekday` strictly after
+/// `current_date` whose week number has the requested parity, so when the next `weekday` is in
+/// a week of the wrong parity, the one a week later is returned. This gives alternating
+/// schedules such as "Tuesdays of odd weeks", in phase with [`biweekly_phase_offset`].
+///
+/// # Arguments
+///
+/// * `reference` - A date in week 0.
+/// * `current_date` - The starting date.
+/// * `weekday` - The target weekday.
+/// * `odd` - `true` for odd weeks after the reference, `false` for even ones.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next matching date. Returns `None` if the calculation
+/// overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_next_weekday_with_week_parity;
+///
+/// let reference = NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(); // A Monday in week 0
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 2).unwrap();
+/// let result = find_next_weekday_with_week_parity(&reference, &current_date, Weekday::Tue, true);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 10));
+/// ```
+pub fn find_next_weekday_with_week_parity(
+    reference: &NaiveDate,
+    current_date: &NaiveDate,
+    weekday: Weekday,
+    odd: bool,
+) -> Option<NaiveDate> {
+    let date = find_next_weekday(current_date, weekday)?;
+    if biweekly_phase_offset(reference, &date) == odd as i64 {
+        Some(date)
+    } else {
+        date.checked_add_days(Days::new(7))
+    }
+}
+
+/// Constructs a date from its parts, reporting which part is invalid.
+///
+/// This is the typed-error counterpart of [`NaiveDate::from_ymd_opt`], and the validation
+/// [`Recurrence::validate`] uses for annual dates.
+///
+/// # Arguments
+///
+/// * `year` - The calendar year.
+/// * `month` - The month (1-12).
+/// * `day` - The day of the month (1-31).
+///
+/// # Errors
+///
+/// * [`NextDayError::InvalidMonth`] if `month` is not between 1 and 12.
+/// * [`NextDayError::InvalidDay`] if the month does not have `day` in `year`, such as February
+///   30th, or February 29th in a non-leap year.
+/// * [`NextDayError::InvalidYear`] if the year is outside the range supported by `NaiveDate`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::{validate_ymd, NextDayError};
+///
+/// assert_eq!(validate_ymd(2024, 2, 29), Ok(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()));
+/// assert_eq!(validate_ymd(2023, 2, 29), Err(NextDayError::InvalidDay(29)));
+/// assert_eq!(validate_ymd(2023, 13, 1), Err(NextDayError::InvalidMonth(13)));
+/// ```
+pub fn validate_ymd(year: i32, month: u32, day: u32) -> Result<NaiveDate, NextDayError> {
+    if !(1..=12).contains(&month) {
+        return Err(NextDayError::InvalidMonth(month));
+    }
+    let month_length = days_in_month(year, month).ok_or(NextDayError::InvalidYear(year))?;
+    if !(1..=month_length).contains(&day) {
+        return Err(NextDayError::InvalidDay(day));
+    }
+    NaiveDate::from_ymd_opt(year, month, day).ok_or(NextDayError::InvalidYear(year))
+}
+
+/// Finds the first date on a specific weekday that is at least `min_days` days away.
+///
+/// The earliest allowed date is `current_date + min_days`, and the result is the first
+/// `weekday` on or after it, for cooldowns such as "the first Monday at least 10 days from
+/// now". If the earliest allowed date is itself on `weekday`, it is returned. Note that with
+/// `min_days = 0` this can return `current_date` itself; use [`find_next_weekday`] for a
+/// strictly later date.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `weekday` - The target weekday.
+/// * `min_days` - The minimum number of days between `current_date` and the result.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the matching date. Returns `None` if the calculation
+/// overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_next_weekday_after_days;
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(); // A Monday
+/// let result = find_next_weekday_after_days(&current_date, Weekday::Mon, 10);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 30));
+/// ```
+pub fn find_next_weekday_after_days(
+    current_date: &NaiveDate,
+    weekday: Weekday,
+    min_days: u64,
+) -> Option<NaiveDate> {
+    let earliest = current_date.checked_add_days(Days::new(min_days))?;
+    earliest.checked_add_days(Days::new(weekday.days_since(earliest.weekday()).into()))
+}
+
+/// Returns the UTC date of a Unix timestamp.
+///
+/// Unix time counts every day as exactly 86,400 seconds, so leap seconds never shift the date
+/// and the result is simply the number of whole days since 1970-01-01. Negative timestamps
+/// are dates before 1970, and a timestamp within a day always maps to that day, so `-1` is
+/// 1969-12-31. For a date in another time zone, convert the timestamp with `chrono` first and
+/// use its `date_naive()`.
+///
+/// # Arguments
+///
+/// * `secs` - The number of seconds since 1970-01-01 00:00:00 UTC.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the UTC date. Returns `None` if the date is outside the
+/// range supported by `NaiveDate`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::{find_next_day_of_month, from_timestamp};
+///
+/// let today = from_timestamp(1_697_328_000).unwrap();
+/// assert_eq!(today, NaiveDate::from_ymd_opt(2023, 10, 15).unwrap());
+/// assert_eq!(
+///     find_next_day_of_month(&today, 1),
+///     NaiveDate::from_ymd_opt(2023, 11, 1)
+/// );
+/// ```
+pub fn from_timestamp(secs: i64) -> Option<NaiveDate> {
+    const SECONDS_PER_DAY: i64 = 86_400;
+    const UNIX_EPOCH_DAYS_FROM_CE: i64 = 719_163;
+
+    let days = secs.div_euclid(SECONDS_PER_DAY) + UNIX_EPOCH_DAYS_FROM_CE;
+    NaiveDate::from_num_days_from_ce_opt(i32::try_from(days).ok()?)
+}
+
+/// Lists the weekdays that occur five times in a month.
+///
+/// The first 28 days of any month contain every weekday four times, so the weekdays occurring
+/// five times are those of the days after the 28th: up to three for a 31-day month, two for a
+/// 30-day month, one for a leap February and none for a 28-day February.
+///
+/// # Arguments
+///
+/// * `year` - The calendar year.
+/// * `month` - The month (1-12).
+///
+/// # Returns
+///
+/// An `Option<Vec<Weekday>>` containing the weekdays in the order they first occur in the
+/// month. Returns `None` if the month is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use next_matching_day::weekdays_occurring_five_times;
+///
+/// // September 2023 starts on a Friday and has 30 days.
+/// let result = weekdays_occurring_five_times(2023, 9);
+/// assert_eq!(result, Some(vec![Weekday::Fri, Weekday::Sat]));
+/// ```
+pub fn weekdays_occurring_five_times(year: i32, month: u32) -> Option<Vec<Weekday>> {
+    let month_length = days_in_month(year, month)?;
+    let first_weekday = NaiveDate::from_ymd_opt(year, month, 1)?.weekday();
+    let weekdays = std::iter::successors(Some(first_weekday), |weekday| Some(weekday.succ()));
+    Some(weekdays.take((month_length - 28) as usize).collect())
+}
+
+/// Finds the nth occurrence of a weekday counting back from the end of a month.
+///
+/// `n = 1` is the last occurrence, as returned by [`find_last_weekday_of_month`], `n = 2` the
+/// second-to-last, and so on. This matches negative ordinals in iCalendar rules, where `-2FR`
+/// is the second-to-last Friday of the month.
+///
+/// # Arguments
+///
+/// * `year` - The calendar year.
+/// * `month` - The month (1-12).
+/// * `weekday` - The target weekday.
+/// * `n` - The 1-based occurrence counted from the end of the month.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the matching date. Returns `None` if `n` is 0, if the
+/// month is invalid, or if the month has fewer than `n` occurrences of the weekday.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_nth_weekday_from_month_end;
+///
+/// // The second-to-last Friday of October 2023.
+/// let result = find_nth_weekday_from_month_end(2023, 10, Weekday::Fri, 2);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 20));
+/// ```
+pub fn find_nth_weekday_from_month_end(
+    year: i32,
+    month: u32,
+    weekday: Weekday,
+    n: u32,
+) -> Option<NaiveDate> {
+    if n == 0 {
+        return None;
+    }
+
+    let last = find_last_weekday_of_month(year, month, weekday)?;
+    let date = last.checked_sub_days(Days::new(7 * (n as u64 - 1)))?;
+
+    if date.month() == month {
+        Some(date)
+    } else {
+        None
+    }
+}
+
+/// Finds the next occurrence of a weekday that falls in an ISO week of the requested parity.
+///
+/// This suits "A-week / B-week" rotations keyed off the ISO week number. Unlike
+/// [`find_next_weekday_with_week_parity`], which counts weeks from a reference date, the parity
+/// restarts with every ISO year, so in years with 53 ISO weeks week 53 and the following
+/// week 1 are both odd, and up to two weeks may be skipped.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `weekday` - The target weekday.
+/// * `even_week` - `true` for even ISO week numbers, `false` for odd ones.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next matching date. Returns `None` if the calculation
+/// overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_next_weekday_in_iso_week_parity;
+///
+/// // Monday the 16th is in ISO week 42, so the next odd-week Monday is a week later.
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 14).unwrap();
+/// let result = find_next_weekday_in_iso_week_parity(&current_date, Weekday::Mon, false);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 23));
+/// ```
+pub fn find_next_weekday_in_iso_week_parity(
+    current_date: &NaiveDate,
+    weekday: Weekday,
+    even_week: bool,
+) -> Option<NaiveDate> {
+    let mut date = find_next_weekday(current_date, weekday)?;
+    while (date.iso_week().week() % 2 == 0) != even_week {
+        date = date.checked_add_days(Days::new(7))?;
+    }
+    Some(date)
+}
+
+/// Returns an iterator over every date from `start` to `end`, both included.
+///
+/// The iterator is empty if `end` is before `start`. It can be combined with
+/// [`filter_recurrence`] to pick out the occurrences of a recurrence within a range.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::dates_in_range;
+///
+/// let start = NaiveDate::from_ymd_opt(2023, 12, 30).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+/// assert_eq!(dates_in_range(&start, &end).count(), 4);
+/// ```
+pub fn dates_in_range(start: &NaiveDate, end: &NaiveDate) -> impl Iterator<Item = NaiveDate> {
+    let end = *end;
+    std::iter::successors(Some(*start), NaiveDate::succ_opt).take_while(move |date| *date <= end)
+}
+
+/// Finds the next occurrence of a day of the month, rolled forward to a business day.
+///
+/// This implements rules such as "the 1st, or the next business day if the 1st is a weekend or
+/// holiday". The anchor is the next occurrence of `day` strictly after `current_date`, as
+/// returned by [`find_next_day_of_month`], and the result is the first business day on or
+/// after the anchor.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `day` - The anchor day of the month (1-31).
+/// * `calendar` - The holidays to skip in addition to weekends.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the rolled date. Returns `None` if the day is invalid or
+/// if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::{find_next_business_day_of_month_anchor, NoHolidays};
+///
+/// // July 1st, 2023 is a Saturday, so it rolls forward to Monday the 3rd.
+/// let current_date = NaiveDate::from_ymd_opt(2023, 6, 15).unwrap();
+/// let result = find_next_business_day_of_month_anchor(&current_date, 1, &NoHolidays);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 7, 3));
+/// ```
+pub fn find_next_business_day_of_month_anchor(
+    current_date: &NaiveDate,
+    day: u32,
+    calendar: &impl HolidayCalendar,
+) -> Option<NaiveDate> {
+    let mut date = find_next_day_of_month(current_date, day)?;
+    while !is_business_day(&date, calendar) {
+        date = date.succ_opt()?;
+    }
+    Some(date)
+}
+
+/// Returns the weekday on which the next occurrence of a fixed date falls.
+///
+/// The occurrence is found with [`find_next_annual_date`], so it is strictly after
+/// `current_date`, and February 29th gives the weekday of the next leap-year occurrence.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `month` - The target month (1-12).
+/// * `day` - The target day (1-31).
+///
+/// # Returns
+///
+/// An `Option<Weekday>` containing the weekday of the next occurrence. Returns `None` if no
+/// occurrence is found, as for [`find_next_annual_date`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::weekday_of_next_annual;
+///
+/// // Christmas 2023 is on a Monday.
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 1).unwrap();
+/// assert_eq!(weekday_of_next_annual(&current_date, 12, 25), Some(Weekday::Mon));
+/// ```
+pub fn weekday_of_next_annual(current_date: &NaiveDate, month: u32, day: u32) -> Option<Weekday> {
+    find_next_annual_date(current_date, month, day).map(|date| date.weekday())
+}
+
+/// Describes in English how long it is until the next occurrence of a weekday.
+///
+/// The result reads "Friday is tomorrow" or "Friday is in 3 days". As with
+/// [`find_next_weekday`], the next occurrence is strictly after `current_date`, so when
+/// `current_date` already falls on `weekday` the countdown is to the same weekday a week later,
+/// "in 7 days".
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::countdown_to_next_weekday;
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 17).unwrap(); // A Tuesday
+/// assert_eq!(countdown_to_next_weekday(&current_date, Weekday::Wed), "Wednesday is tomorrow");
+/// assert_eq!(countdown_to_next_weekday(&current_date, Weekday::Fri), "Friday is in 3 days");
+/// ```
+pub fn countdown_to_next_weekday(current_date: &NaiveDate, weekday: Weekday) -> String {
+    let name = match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    };
+    match days_forward_to(current_date.weekday(), weekday) {
+        1 => format!("{name} is tomorrow"),
+        days => format!("{name} is in {days} days"),
+    }
+}
+
+/// Finds the next occurrence of a day of the month that is no earlier than a given month.
+///
+/// This answers queries such as "the next 15th, but not before March". The result is the next
+/// occurrence of `day` strictly after `current_date`, as returned by [`find_next_day_of_month`],
+/// unless that falls before `min_month` of `min_year`, in which case it is the first occurrence
+/// on or after the 1st of that month, as returned by [`find_next_day_of_month_from_ym`].
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `day` - The target day of the month (1-31).
+/// * `min_month` - The earliest allowed month (1-12).
+/// * `min_year` - The year of the earliest allowed month.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next matching date. Returns `None` if the day or
+/// month is invalid or if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::find_next_day_of_month_not_before_month;
+///
+/// // The next 15th would be January 15th, but nothing before March is allowed.
+/// let current_date = NaiveDate::from_ymd_opt(2024, 1, 3).unwrap();
+/// let result = find_next_day_of_month_not_before_month(&current_date, 15, 3, 2024);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 3, 15));
+/// ```
+pub fn find_next_day_of_month_not_before_month(
+    current_date: &NaiveDate,
+    day: u32,
+    min_month: u32,
+    min_year: i32,
+) -> Option<NaiveDate> {
+    let minimum = NaiveDate::from_ymd_opt(min_year, min_month, 1)?;
+    let next = find_next_day_of_month(current_date, day)?;
+    if next >= minimum {
+        Some(next)
+    } else {
+        find_next_day_of_month_from_ym(min_year, min_month, day)
+    }
+}
+
+/// Returns which occurrence of its weekday a date is within its month.
+///
+/// The result is 1 for the first occurrence, such as the first Thursday, up to 5 for a fifth
+/// occurrence. This is the inverse of [`find_nth_weekday_of_month`]: for any date,
+/// `find_nth_weekday_of_month(date.year(), date.month(), date.weekday(), week_of_month(&date))`
+/// is `date` again.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::week_of_month;
+///
+/// // November 23rd, 2023 is the fourth Thursday of the month.
+/// let date = NaiveDate::from_ymd_opt(2023, 11, 23).unwrap();
+/// assert_eq!(week_of_month(&date), 4);
+/// ```
+pub fn week_of_month(date: &NaiveDate) -> u32 {
+    (date.day() - 1) / 7 + 1
+}
+
+/// Finds the next date whose weekday is set in a 7-bit weekday mask.
+///
+/// Bit 0 stands for Monday, bit 1 for Tuesday and so on up to bit 6 for Sunday, so
+/// `0b0010101` is Monday, Wednesday and Friday. The result is the soonest date strictly after
+/// `current_date` whose weekday bit is set; bit 7 is ignored.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `mask` - The weekdays to match, one bit per weekday starting from Monday.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next matching date. Returns `None` if no weekday bit is
+/// set or if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::find_next_weekday_mask;
+///
+/// let tuesday = NaiveDate::from_ymd_opt(2023, 10, 17).unwrap();
+/// let result = find_next_weekday_mask(&tuesday, 0b0010101); // Monday, Wednesday and Friday
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 18));
+/// ```
+pub fn find_next_weekday_mask(current_date: &NaiveDate, mask: u8) -> Option<NaiveDate> {
+    let mask = mask & 0b111_1111;
+    if mask == 0 {
+        return None;
+    }
+
+    let mut date = *current_date;
+    loop {
+        date = date.succ_opt()?;
+        if mask & (1 << date.weekday().num_days_from_monday()) != 0 {
+            return Some(date);
+        }
+    }
+}
+
+/// Lists the months between two dates that do not have a given day of the month.
+///
+/// This explains gaps in a day-of-month schedule: billing on the 31st jumps from January 31st to
+/// March 31st because February has no 31st. Only the months strictly between the months of `a`
+/// and `b` are considered, so the months of the two dates themselves are never listed.
+///
+/// # Arguments
+///
+/// * `a` - The earlier occurrence.
+/// * `b` - The later occurrence.
+/// * `day` - The day of the month (1-31).
+///
+/// # Returns
+///
+/// A `Vec<u32>` of month numbers (1-12), in chronological order, that lack `day`. The result is
+/// empty if `b` is not in a later month than `a`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::skipped_months_between;
+///
+/// let a = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+/// let b = NaiveDate::from_ymd_opt(2023, 3, 31).unwrap();
+/// assert_eq!(skipped_months_between(&a, &b, 31), vec![2]);
+/// ```
+pub fn skipped_months_between(a: &NaiveDate, b: &NaiveDate, day: u32) -> Vec<u32> {
+    let (Some(start), Some(end)) = (a.with_day(1), b.with_day(1)) else {
+        return Vec::new();
+    };
+
+    let mut skipped = Vec::new();
+    let mut month = start;
+    while let Some(next) = month.checked_add_months(Months::new(1)) {
+        if next >= end {
+            break;
+        }
+        month = next;
+        if days_in_month(month.year(), month.month()).is_some_and(|days| days < day) {
+            skipped.push(month.month());
+        }
+    }
+    skipped
+}
+
+/// Finds the next occurrence of a weekday that falls in the requested half of its month.
+///
+/// The first half of a month is days 1 to 15 and the second half is day 16 to the end of the
+/// month. The search starts at the next occurrence of `weekday` strictly after `current_date` and
+/// advances a week at a time, so an occurrence in the wrong half is skipped in favor of a later
+/// week, possibly in the following month.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `weekday` - The target weekday.
+/// * `first_half` - `true` for days 1 to 15, `false` for day 16 onwards.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next matching date. Returns `None` if the calculation
+/// overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::find_next_weekday_in_month_half;
+///
+/// // Tuesdays on October 24th and 31st are in the second half, so November 7th is next.
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 17).unwrap();
+/// let result = find_next_weekday_in_month_half(&current_date, Weekday::Tue, true);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 7));
+/// ```
+pub fn find_next_weekday_in_month_half(
+    current_date: &NaiveDate,
+    weekday: Weekday,
+    first_half: bool,
+) -> Option<NaiveDate> {
+    // Each half spans at least 13 days, so a match is never more than 3 weeks past the first.
+    let mut date = find_next_weekday(current_date, weekday)?;
+    for _ in 0..4 {
+        if (date.day() <= 15) == first_half {
+            return Some(date);
+        }
+        date = date.checked_add_days(Days::new(7))?;
+    }
+
+    None
+}
+
+/// Finds the next occurrence of an annual date, together with an English label for it.
+///
+/// The label, such as "February 29, 2028", is formatted from the resolved date rather than the
+/// requested month and day, so it always names the year the event actually falls in, including
+/// when a February 29th request skips ahead to the next leap year.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `month` - The target month (1-12).
+/// * `day` - The target day (1-31).
+///
+/// # Returns
+///
+/// An `Option<(NaiveDate, String)>` containing the next matching date and its label. Returns
+/// `None` in the same cases as [`find_next_annual_date`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::find_next_annual_date_labeled;
+///
+/// let current_date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+/// let (date, label) = find_next_annual_date_labeled(&current_date, 2, 29).unwrap();
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2028, 2, 29).unwrap());
+/// assert_eq!(label, "February 29, 2028");
+/// ```
+pub fn find_next_annual_date_labeled(
+    current_date: &NaiveDate,
+    month: u32,
+    day: u32,
+) -> Option<(NaiveDate, String)> {
+    let date = find_next_annual_date(current_date, month, day)?;
+    Some((date, date.format("%B %-d, %Y").to_string()))
+}
+
+/// Returns `true` if `n` is a prime number, by trial division.
+fn is_prime(n: u32) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+}
+
+/// Finds the next date whose ordinal day of the year is a prime number.
+///
+/// The ordinal counts from 1 on January 1st, so January 2nd (day 2) is the first match of every
+/// year and January 1st never matches. The search is bounded to a year of days, which always
+/// contains a match.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next matching date strictly after `current_date`.
+/// Returns `None` if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::find_next_prime_day_of_year;
+///
+/// // February 1st is day 32, and the next prime is day 37, February 6th.
+/// let current_date = NaiveDate::from_ymd_opt(2023, 2, 1).unwrap();
+/// let result = find_next_prime_day_of_year(&current_date);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 2, 6));
+/// ```
+pub fn find_next_prime_day_of_year(current_date: &NaiveDate) -> Option<NaiveDate> {
+    find_next_matching(current_date, 366, |date| is_prime(date.ordinal()))
+}
+
+/// Returns the shortest signed number of days from one weekday to another.
+///
+/// The result is between -3 and 3: positive when the shortest way from `from` to `to` is
+/// forward, negative when it is backward, and 0 for the same weekday. A week has an odd number of
+/// days, so the two directions never tie: when `to` is 3 days forward it is 4 days backward, and
+/// the shorter forward distance, 3, is returned, while 4 days forward is returned as -3.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use next_matching_day::signed_weekday_distance;
+///
+/// assert_eq!(signed_weekday_distance(Weekday::Mon, Weekday::Thu), 3);
+/// assert_eq!(signed_weekday_distance(Weekday::Mon, Weekday::Fri), -3);
+/// assert_eq!(signed_weekday_distance(Weekday::Sun, Weekday::Mon), 1);
+/// ```
+pub fn signed_weekday_distance(from: Weekday, to: Weekday) -> i8 {
+    let forward = to.days_since(from) as i8;
+    if forward <= 3 {
+        forward
+    } else {
+        forward - 7
+    }
+}
+
+/// Finds the next date whose day of the month falls within a window of days.
+///
+/// The result is the soonest date strictly after `current_date` with a day of the month between
+/// `min_day` and `max_day`, both inclusive. Within the window this is simply the next day, and
+/// past the window it is `min_day` of the next month that has it, so for a window from the 10th
+/// to the 20th, the 21st is followed by the 10th of the next month.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `min_day` - The first day of the window (1-31).
+/// * `max_day` - The last day of the window, at least `min_day`.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the next matching date. Returns `None` if `min_day` is 0 or
+/// after `max_day`, if `min_day` is greater than 31, or if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::find_next_day_in_window;
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 25).unwrap();
+/// let result = find_next_day_in_window(&current_date, 10, 20);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 10));
+/// ```
+pub fn find_next_day_in_window(
+    current_date: &NaiveDate,
+    min_day: u32,
+    max_day: u32,
+) -> Option<NaiveDate> {
+    if min_day == 0 || min_day > max_day {
+        return None;
+    }
+
+    let next = current_date.succ_opt()?;
+    if (min_day..=max_day).contains(&next.day()) {
+        Some(next)
+    } else {
+        find_next_day_of_month(&next, min_day)
+    }
+}
+
+/// Returns the ISO 8601 number of a date's weekday, from 1 for Monday to 7 for Sunday.
+///
+/// This is how far the date is into its ISO week, as used with [`same_iso_week`] and
+/// [`find_next_weekday_in_iso_week_parity`]. It is the same as chrono's
+/// [`Weekday::number_from_monday`], under a name that keeps call sites self-documenting.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::iso_weekday_number;
+///
+/// let monday = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap();
+/// let sunday = NaiveDate::from_ymd_opt(2023, 10, 22).unwrap();
+/// assert_eq!(iso_weekday_number(&monday), 1);
+/// assert_eq!(iso_weekday_number(&sunday), 7);
+/// ```
+pub fn iso_weekday_number(date: &NaiveDate) -> u32 {
+    date.weekday().number_from_monday()
+}
+
+/// Finds the first day of the week containing the next occurrence of an annual date.
+///
+/// This anchors events to "the week of July 4th". The annual date is found with
+/// [`find_next_annual_date`], and the result is the latest `week_start` weekday on or before it,
+/// which is the annual date itself when it falls on `week_start`. The result can therefore be on
+/// or before `current_date` when the annual date is only a few days away.
+///
+/// # Arguments
+///
+/// * `current_date` - The starting date.
+/// * `month` - The target month (1-12).
+/// * `day` - The target day (1-31).
+/// * `week_start` - The weekday that weeks start on.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the start of the week. Returns `None` if the annual date is
+/// invalid or if the calculation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::week_start_of_annual;
+///
+/// // July 4th, 2024 is a Thursday, in the week starting on Monday, July 1st.
+/// let current_date = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+/// let result = week_start_of_annual(&current_date, 7, 4, Weekday::Mon);
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 7, 1));
+/// ```
+pub fn week_start_of_annual(
+    current_date: &NaiveDate,
+    month: u32,
+    day: u32,
+    week_start: Weekday,
+) -> Option<NaiveDate> {
+    let date = find_next_annual_date(current_date, month, day)?;
+    let days_since = date.weekday().days_since(week_start);
+    date.checked_sub_days(Days::new(days_since.into()))
+}
+
+/// Converts a date to its epoch day, the number of days from the common era.
+///
+/// Epoch days count like chrono's [`Datelike::num_days_from_ce`]: January 1st of year 1 is day 1,
+/// and earlier dates are 0 or negative. [`epoch_day_to_date`] is the inverse.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::date_to_epoch_day;
+///
+/// assert_eq!(date_to_epoch_day(&NaiveDate::from_ymd_opt(1, 1, 1).unwrap()), 1);
+/// assert_eq!(date_to_epoch_day(&NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()), 719_163);
+/// ```
+pub fn date_to_epoch_day(date: &NaiveDate) -> i32 {
+    date.num_days_from_ce()
+}
+
+/// Converts an epoch day, as returned by [`date_to_epoch_day`], back to a date.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the date. Returns `None` if the epoch day is outside the
+/// range of `NaiveDate`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::epoch_day_to_date;
+///
+/// assert_eq!(epoch_day_to_date(719_163), NaiveDate::from_ymd_opt(1970, 1, 1));
+/// ```
+pub fn epoch_day_to_date(epoch_day: i32) -> Option<NaiveDate> {
+    NaiveDate::from_num_days_from_ce_opt(epoch_day)
+}
+
+/// Calculates the next epoch day that falls on a specific weekday.
+///
+/// This is [`find_next_weekday`] for systems that store dates as epoch days, as defined by
+/// [`date_to_epoch_day`], computed on the integers alone without constructing a `NaiveDate`. As
+/// with `find_next_weekday`, the result is strictly after `current_epoch_day`, so an epoch day
+/// already on `weekday` gives the same weekday a week later.
+///
+/// # Arguments
+///
+/// * `current_epoch_day` - The starting epoch day.
+/// * `weekday` - The target weekday.
+///
+/// # Returns
+///
+/// An `Option<i32>` containing the next matching epoch day. Returns `None` if it overflows `i32`.
+/// The result is not checked against the range of `NaiveDate`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use next_matching_day::next_weekday_epoch_day;
+///
+/// // Epoch day 1, January 1st of year 1, is a Monday.
+/// assert_eq!(next_weekday_epoch_day(1, Weekday::Wed), Some(3));
+/// assert_eq!(next_weekday_epoch_day(1, Weekday::Mon), Some(8));
+/// ```
+pub fn next_weekday_epoch_day(current_epoch_day: i32, weekday: Weekday) -> Option<i32> {
+    // Epoch day 1 is a Monday, so epoch day 0 is a Sunday.
+    let days_from_monday = (i64::from(current_epoch_day) - 1).rem_euclid(7) as u8;
+    let current_weekday = Weekday::try_from(days_from_monday).ok()?;
+    let days = days_forward_to(current_weekday, weekday);
+    current_epoch_day.checked_add(days as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, Weekday};
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_find_next_weekday() {
+        // Test case 1: Next weekday is the next day
+        let date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // Sunday
+        let result = find_next_weekday_ref(&date, &Weekday::Mon).unwrap(); // Next Monday
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 16).unwrap());
+
+        // Test case 2: Next weekday is in the next week
+        let date = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(); // Monday
+        let result = find_next_weekday_ref(&date, &Weekday::Sun).unwrap(); // Next Sunday
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 22).unwrap());
+
+        // Test case 3: Next weekday is the same day
+        let date = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(); // Monday
+        let result = find_next_weekday_ref(&date, &Weekday::Mon).unwrap(); // Next Monday
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 23).unwrap());
+
+        // Test case 4: Next weekday is in the next month
+        let date = NaiveDate::from_ymd_opt(2023, 10, 30).unwrap(); // Monday
+        let result = find_next_weekday_ref(&date, &Weekday::Sun).unwrap(); // Next Sunday
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 5).unwrap());
+
+        // Test case 5: Next weekday is in the next year
+        let date = NaiveDate::from_ymd_opt(2023, 12, 28).unwrap(); // Thursday
+        let result = find_next_weekday_ref(&date, &Weekday::Wed).unwrap(); // Next Wednesday
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 1, 3).unwrap());
+
+        // Test case 6: Leap year, before Feb 29
+        let date = NaiveDate::from_ymd_opt(2024, 2, 26).unwrap(); // Monday
+        let result = find_next_weekday_ref(&date, &Weekday::Wed).unwrap(); // Next Wednesday
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 2, 28).unwrap());
+
+        // Test case 7: Leap year, on Feb 28
+        let date = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap(); // Wednesday
+        let result = find_next_weekday_ref(&date, &Weekday::Thu).unwrap(); // Next Thursday
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+
+        // Test case 8: Leap year, on Feb 29
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(); // Thursday
+        let result = find_next_weekday_ref(&date, &Weekday::Fri).unwrap(); // Next Friday
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 3, 1).unwrap());
+    }
+
+    #[test]
+    fn test_find_next_day_of_month() {
+        // Test case 1: Next day is in the same month
+        let date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
+        let result = find_next_day_of_month(&date, 20).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 20).unwrap());
+
+        // Test case 2: Next day is in the next month
+        let date = NaiveDate::from_ymd_opt(2023, 10, 25).unwrap();
+        let result = find_next_day_of_month(&date, 10).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 10).unwrap());
+
+        // Test case 3: Current day is the same as next_day, should find it in the next month
+        let date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
+        let result = find_next_day_of_month(&date, 15).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 15).unwrap());
+
+        // Test case 4: Next day is 31, current month is short (September)
+        let date = NaiveDate::from_ymd_opt(2023, 9, 15).unwrap();
+        let result = find_next_day_of_month(&date, 31).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 31).unwrap());
+
+        // Test case 5: Next day is 31, current month is January, next month is February (short)
+        let date = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+        let result = find_next_day_of_month(&date, 31).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 3, 31).unwrap());
+
+        // Test case 6: Leap year, looking for the 29th from Feb 1st.
+        let date = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+        let result = find_next_day_of_month(&date, 29).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+
+        // Test case 7: Non-leap year, looking for the 29th from Feb 1st.
+        let date = NaiveDate::from_ymd_opt(2023, 2, 1).unwrap();
+        let result = find_next_day_of_month(&date, 29).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 3, 29).unwrap());
+    }
+
+    #[test]
+    fn test_find_next_annual_date() {
+        // Test case 1: Target date is in the same year and after the current date
+        let date = NaiveDate::from_ymd_opt(2023, 5, 15).unwrap();
+        let result = find_next_annual_date(&date, 6, 20).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 6, 20).unwrap());
+
+        // Test case 2: Target date is in the same month and day as the current date, so it returns the next year's date
+        let date = NaiveDate::from_ymd_opt(2023, 5, 15).unwrap();
+        let result = find_next_annual_date(&date, 5, 15).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 5, 15).unwrap());
+
+        // Test case 3: Target date is in the same month but earlier in the month, so it returns the next year's date
+        let date = NaiveDate::from_ymd_opt(2023, 5, 15).unwrap();
+        let result = find_next_annual_date(&date, 5, 13).unwrap();
         assert_eq!(result, NaiveDate::from_ymd_opt(2024, 5, 13).unwrap());
 
-        // Test case 4: Target date is February 29, and the current date is after February 29 in a leap year; returns the next leap year's date
-        let date = NaiveDate::from_ymd_opt(2024, 3, 20).unwrap();
-        let result = find_next_annual_date(&date, 2, 29).unwrap();
-        assert_eq!(result, NaiveDate::from_ymd_opt(2028, 2, 29).unwrap());
+        // Test case 4: Target date is February 29, and the current date is after February 29 in a leap year; returns the next leap year's date
+        let date = NaiveDate::from_ymd_opt(2024, 3, 20).unwrap();
+        let result = find_next_annual_date(&date, 2, 29).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2028, 2, 29).unwrap());
+
+        // Test case 5: Target date is February 29, and the current date is in a non-leap year; returns the next leap year's date
+        let date = NaiveDate::from_ymd_opt(2025, 2, 20).unwrap();
+        let result = find_next_annual_date(&date, 2, 29).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2028, 2, 29).unwrap());
+    }
+
+    #[test]
+    fn test_find_next_same_weekday_with_day_parity() {
+        // Test case 1: Parity aligns one week out
+        let date = NaiveDate::from_ymd_opt(2023, 10, 10).unwrap(); // Tuesday
+        let result = find_next_same_weekday_with_day_parity(&date, false).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 17).unwrap());
+
+        // Test case 2: Same parity as the current date skips two weeks
+        let date = NaiveDate::from_ymd_opt(2023, 1, 17).unwrap(); // Tuesday
+        let result = find_next_same_weekday_with_day_parity(&date, false).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 1, 31).unwrap());
+
+        // Test case 3: Crossing a 31-day month keeps the parity, so two weeks are needed
+        let date = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(); // Tuesday
+        let result = find_next_same_weekday_with_day_parity(&date, true).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 2, 14).unwrap());
+
+        // Test case 4: Crossing a 30-day month flips the parity as usual
+        let date = NaiveDate::from_ymd_opt(2023, 4, 27).unwrap(); // Thursday
+        let result = find_next_same_weekday_with_day_parity(&date, true).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 5, 4).unwrap());
+
+        // Test case 5: The result always keeps the starting weekday
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(); // Thursday
+        let result = find_next_same_weekday_with_day_parity(&date, true).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 3, 14).unwrap());
+        assert_eq!(result.weekday(), Weekday::Thu);
+    }
+
+    #[test]
+    fn test_find_next_weekday_by_name() {
+        let date = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(); // Monday
+
+        // Test case 1: Full name
+        let result = find_next_weekday_by_name(&date, "Tuesday").unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 17).unwrap());
+
+        // Test case 2: Lowercase abbreviation
+        let result = find_next_weekday_by_name(&date, "tue").unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 17).unwrap());
+
+        // Test case 3: Uppercase abbreviation
+        let result = find_next_weekday_by_name(&date, "WED").unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 18).unwrap());
+
+        // Test case 4: Same weekday as the current date moves to the next week
+        let result = find_next_weekday_by_name(&date, "monday").unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 23).unwrap());
+
+        // Test case 5: Garbage input
+        assert_eq!(find_next_weekday_by_name(&date, "blursday"), None);
+        assert_eq!(find_next_weekday_by_name(&date, ""), None);
+    }
+
+    #[test]
+    fn test_find_previous_weekday() {
+        // Test case 1: Previous weekday is the previous day
+        let date = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(); // Monday
+        let result = find_previous_weekday(&date, Weekday::Sun).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 15).unwrap());
+
+        // Test case 2: Previous weekday is the same day, so it returns the previous week
+        let date = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(); // Monday
+        let result = find_previous_weekday(&date, Weekday::Mon).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 9).unwrap());
+
+        // Test case 3: Previous weekday is in the previous year
+        let date = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(); // Tuesday
+        let result = find_previous_weekday(&date, Weekday::Wed).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 12, 27).unwrap());
+
+        // Test case 4: Leap year, on Mar 1
+        let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(); // Friday
+        let result = find_previous_weekday(&date, Weekday::Thu).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+    }
+
+    #[test]
+    fn test_weekday_iter() {
+        let start = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // Sunday
+        let dates: Vec<_> = weekday_iter(start, Weekday::Sun).take(3).collect();
+        assert_eq!(
+            dates,
+            [
+                NaiveDate::from_ymd_opt(2023, 10, 22).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 10, 29).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 11, 5).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_weekday_iter_rev() {
+        let start = NaiveDate::from_ymd_opt(2023, 10, 18).unwrap(); // Wednesday
+        let mut iter = weekday_iter_rev(start, Weekday::Fri);
+
+        // The first item matches find_previous_weekday.
+        let first = iter.next().unwrap();
+        assert_eq!(first, find_previous_weekday(&start, Weekday::Fri).unwrap());
+        assert_eq!(first, NaiveDate::from_ymd_opt(2023, 10, 13).unwrap());
+
+        // Each following item is 7 days earlier.
+        let mut previous = first;
+        for date in iter.take(10) {
+            assert_eq!(previous - date, chrono::TimeDelta::days(7));
+            assert_eq!(date.weekday(), Weekday::Fri);
+            previous = date;
+        }
+
+        // The iterator ends at the minimum representable date instead of panicking.
+        assert!(weekday_iter_rev(NaiveDate::MIN, Weekday::Mon)
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn test_find_next_from_annual_set() {
+        let quarter_days = [(3, 25), (6, 24), (9, 29), (12, 25)];
+
+        // Test case 1: Between two quarter days
+        let date = NaiveDate::from_ymd_opt(2023, 7, 1).unwrap();
+        let result = find_next_from_annual_set(&date, &quarter_days).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 9, 29).unwrap());
+
+        // Test case 2: On a quarter day, so it returns the following one
+        let date = NaiveDate::from_ymd_opt(2023, 6, 24).unwrap();
+        let result = find_next_from_annual_set(&date, &quarter_days).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 9, 29).unwrap());
+
+        // Test case 3: After the last quarter day, so it rolls into the next year
+        let date = NaiveDate::from_ymd_opt(2023, 12, 26).unwrap();
+        let result = find_next_from_annual_set(&date, &quarter_days).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 3, 25).unwrap());
+
+        // Test case 4: The order of the set does not matter
+        let date = NaiveDate::from_ymd_opt(2023, 7, 1).unwrap();
+        let result = find_next_from_annual_set(&date, &[(12, 25), (9, 29), (3, 25)]).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 9, 29).unwrap());
+
+        // Test case 5: An invalid pair is rejected
+        let result = find_next_from_annual_set(&date, &[(9, 29), (2, 30)]);
+        assert_eq!(result, None);
+
+        // Test case 6: An empty set has no next date
+        assert_eq!(find_next_from_annual_set(&date, &[]), None);
+    }
+
+    #[test]
+    fn test_find_previous_day_of_month() {
+        // Test case 1: Previous day is in the same month
+        let date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
+        let result = find_previous_day_of_month(&date, 10).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 10).unwrap());
+
+        // Test case 2: Previous day is in the previous month
+        let date = NaiveDate::from_ymd_opt(2023, 10, 5).unwrap();
+        let result = find_previous_day_of_month(&date, 10).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 9, 10).unwrap());
+
+        // Test case 3: Current day is the same as previous_day, should find it in the previous month
+        let date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
+        let result = find_previous_day_of_month(&date, 15).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 9, 15).unwrap());
+
+        // Test case 4: Previous day is 31, skipping February
+        let date = NaiveDate::from_ymd_opt(2023, 3, 31).unwrap();
+        let result = find_previous_day_of_month(&date, 31).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 1, 31).unwrap());
+
+        // Test case 5: Previous day is in the previous year
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        let result = find_previous_day_of_month(&date, 20).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 12, 20).unwrap());
+
+        // Test case 6: Invalid day
+        let date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
+        assert_eq!(find_previous_day_of_month(&date, 32), None);
+    }
+
+    #[test]
+    fn test_find_previous_annual_date() {
+        // Test case 1: Target date is earlier in the same year
+        let date = NaiveDate::from_ymd_opt(2023, 8, 1).unwrap();
+        let result = find_previous_annual_date(&date, 7, 1).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 7, 1).unwrap());
+
+        // Test case 2: Target date is the current date, so it returns the previous year's date
+        let date = NaiveDate::from_ymd_opt(2023, 5, 15).unwrap();
+        let result = find_previous_annual_date(&date, 5, 15).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2022, 5, 15).unwrap());
+
+        // Test case 3: Target date is later in the year, so it returns the previous year's date
+        let date = NaiveDate::from_ymd_opt(2023, 5, 15).unwrap();
+        let result = find_previous_annual_date(&date, 6, 20).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2022, 6, 20).unwrap());
+
+        // Test case 4: February 29 before the leap day of a leap year; returns the previous leap year's date
+        let date = NaiveDate::from_ymd_opt(2024, 2, 20).unwrap();
+        let result = find_previous_annual_date(&date, 2, 29).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2020, 2, 29).unwrap());
+    }
+
+    #[test]
+    fn test_find_next_annual_date_proleptic_years() {
+        // Test case 1: Year 0 is a leap year in the proleptic Gregorian calendar
+        let date = NaiveDate::from_ymd_opt(-1, 6, 1).unwrap();
+        let result = find_next_annual_date(&date, 2, 29).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(0, 2, 29).unwrap());
+
+        // Test case 2: After the year 0 leap day, the next one is in year 4
+        let date = NaiveDate::from_ymd_opt(0, 3, 1).unwrap();
+        let result = find_next_annual_date(&date, 2, 29).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(4, 2, 29).unwrap());
+
+        // Test case 3: A BCE date; -100 is not a leap year, so the search spans 8 years
+        let date = NaiveDate::from_ymd_opt(-104, 3, 1).unwrap();
+        let result = find_next_annual_date(&date, 2, 29).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(-96, 2, 29).unwrap());
+
+        // Test case 4: A BCE date; -400 is a leap year
+        let date = NaiveDate::from_ymd_opt(-401, 12, 31).unwrap();
+        let result = find_next_annual_date(&date, 2, 29).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(-400, 2, 29).unwrap());
+
+        // Test case 5: A regular date crossing from year -1 into year 0
+        let date = NaiveDate::from_ymd_opt(-1, 12, 31).unwrap();
+        let result = find_next_annual_date(&date, 1, 1).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(0, 1, 1).unwrap());
+
+        // Test case 6: Searching backward across year 0 follows the same rules
+        let date = NaiveDate::from_ymd_opt(4, 1, 1).unwrap();
+        let result = find_previous_annual_date(&date, 2, 29).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(0, 2, 29).unwrap());
+    }
+
+    #[test]
+    fn test_find_nth_weekday_of_year() {
+        // Test case 1: First occurrence, which is not January 1
+        let result = find_nth_weekday_of_year(2023, Weekday::Mon, 1).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 1, 2).unwrap());
+
+        // Test case 2: First occurrence on January 1
+        let result = find_nth_weekday_of_year(2023, Weekday::Sun, 1).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 1, 1).unwrap());
+
+        // Test case 3: A mid-year occurrence
+        let result = find_nth_weekday_of_year(2023, Weekday::Mon, 26).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 6, 26).unwrap());
+
+        // Test case 4: The 53rd occurrence exists in a leap year starting on that weekday
+        let result = find_nth_weekday_of_year(2024, Weekday::Mon, 53).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 12, 30).unwrap());
+
+        // Test case 5: The 53rd occurrence does not exist for other weekdays
+        assert_eq!(find_nth_weekday_of_year(2024, Weekday::Wed, 53), None);
+
+        // Test case 6: No year has a 54th occurrence
+        assert_eq!(find_nth_weekday_of_year(2024, Weekday::Mon, 54), None);
+
+        // Test case 7: The 0th occurrence is invalid
+        assert_eq!(find_nth_weekday_of_year(2024, Weekday::Mon, 0), None);
+    }
+
+    #[test]
+    fn test_find_next_weekday_crossing() {
+        // Test case 1: Within the same month
+        let date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // Sunday
+        let result = find_next_weekday_crossing(&date, Weekday::Mon).unwrap();
+        assert_eq!(
+            result,
+            (NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(), false, false)
+        );
+
+        // Test case 2: Into the next month
+        let date = NaiveDate::from_ymd_opt(2023, 10, 30).unwrap(); // Monday
+        let result = find_next_weekday_crossing(&date, Weekday::Sun).unwrap();
+        assert_eq!(
+            result,
+            (NaiveDate::from_ymd_opt(2023, 11, 5).unwrap(), true, false)
+        );
+
+        // Test case 3: Into the next year
+        let date = NaiveDate::from_ymd_opt(2023, 12, 28).unwrap(); // Thursday
+        let result = find_next_weekday_crossing(&date, Weekday::Wed).unwrap();
+        assert_eq!(
+            result,
+            (NaiveDate::from_ymd_opt(2024, 1, 3).unwrap(), true, true)
+        );
+
+        // Test case 4: Overflow
+        assert_eq!(
+            find_next_weekday_crossing(&NaiveDate::MAX, Weekday::Mon),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_next_day_of_month_adjusted() {
+        // October 1st, 2023 is a Sunday.
+        let date = NaiveDate::from_ymd_opt(2023, 9, 15).unwrap();
+
+        // Test case 1: The 1st on a Sunday under the preceding rule
+        let result = find_next_day_of_month_adjusted(&date, 1, WeekendRule::Preceding).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 9, 29).unwrap());
+
+        // Test case 2: The 1st on a Sunday under the following rule
+        let result = find_next_day_of_month_adjusted(&date, 1, WeekendRule::Following).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 2).unwrap());
+
+        // Test case 3: A target day on a weekday is not adjusted
+        let result = find_next_day_of_month_adjusted(&date, 20, WeekendRule::Following).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 9, 20).unwrap());
+
+        // Test case 4: The preceding adjustment would land on the current date, so the next month is used
+        let date = NaiveDate::from_ymd_opt(2023, 9, 29).unwrap();
+        let result = find_next_day_of_month_adjusted(&date, 1, WeekendRule::Preceding).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 1).unwrap());
+
+        // Test case 5: The following adjustment can move the result into the next month
+        let date = NaiveDate::from_ymd_opt(2023, 12, 1).unwrap();
+        let result = find_next_day_of_month_adjusted(&date, 31, WeekendRule::Following).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+
+        // Test case 6: Invalid day
+        assert_eq!(
+            find_next_day_of_month_adjusted(&date, 32, WeekendRule::Following),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_weekday_of_term_week() {
+        // The term starts on Wednesday, September 6th.
+        let term_start = NaiveDate::from_ymd_opt(2023, 9, 6).unwrap();
+
+        // Test case 1: The weekday is the week start
+        let result = find_weekday_of_term_week(&term_start, 2, Weekday::Mon, Weekday::Mon).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 9, 11).unwrap());
+
+        // Test case 2: The weekday follows the week start within the week
+        let result = find_weekday_of_term_week(&term_start, 2, Weekday::Sun, Weekday::Mon).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 9, 17).unwrap());
+
+        // Test case 3: Weeks starting on Sunday
+        let result = find_weekday_of_term_week(&term_start, 2, Weekday::Sat, Weekday::Sun).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 9, 16).unwrap());
+
+        // Test case 4: The weekday precedes the week start, so it is the last days of the week
+        let result = find_weekday_of_term_week(&term_start, 2, Weekday::Mon, Weekday::Wed).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 9, 18).unwrap());
+
+        // Test case 5: Week 1 can return a date before the term start
+        let result = find_weekday_of_term_week(&term_start, 1, Weekday::Mon, Weekday::Mon).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 9, 4).unwrap());
+
+        // Test case 6: Week 0 is invalid
+        assert_eq!(
+            find_weekday_of_term_week(&term_start, 0, Weekday::Mon, Weekday::Mon),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_nth_business_day_of_month() {
+        // Test case 1: The 1st is a weekday
+        let result = find_nth_business_day_of_month(2023, 11, 1, &NoHolidays).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 1).unwrap());
+
+        // Test case 2: The 1st is a Sunday
+        let result = find_nth_business_day_of_month(2023, 10, 1, &NoHolidays).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 2).unwrap());
+        let result = find_nth_business_day_of_month(2023, 10, 3, &NoHolidays).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 4).unwrap());
+
+        // Test case 3: A holiday shifts the result
+        let holidays = [NaiveDate::from_ymd_opt(2023, 10, 3).unwrap()];
+        let result = find_nth_business_day_of_month(2023, 10, 3, &holidays).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 5).unwrap());
+
+        // Test case 4: The last business day of the month
+        let result = find_nth_business_day_of_month(2023, 10, 22, &NoHolidays).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 31).unwrap());
+
+        // Test case 5: The month has fewer business days than requested
+        assert_eq!(
+            find_nth_business_day_of_month(2023, 10, 23, &NoHolidays),
+            None
+        );
+
+        // Test case 6: Invalid inputs
+        assert_eq!(
+            find_nth_business_day_of_month(2023, 10, 0, &NoHolidays),
+            None
+        );
+        assert_eq!(
+            find_nth_business_day_of_month(2023, 13, 1, &NoHolidays),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_last_weekday_of_month() {
+        // Test case 1: The last day of the month is the weekday
+        let result = find_last_weekday_of_month(2023, 10, Weekday::Tue).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 31).unwrap());
+
+        // Test case 2: The weekday is earlier in the last week
+        let result = find_last_weekday_of_month(2023, 10, Weekday::Wed).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 25).unwrap());
+
+        // Test case 3: Leap year February
+        let result = find_last_weekday_of_month(2024, 2, Weekday::Thu).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+
+        // Test case 4: December
+        let result = find_last_weekday_of_month(2023, 12, Weekday::Fri).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 12, 29).unwrap());
+
+        // Test case 5: Invalid month
+        assert_eq!(find_last_weekday_of_month(2023, 13, Weekday::Fri), None);
+    }
+
+    #[test]
+    fn test_is_last_weekday_of_month() {
+        // Test case 1: The 4th of 5 Tuesdays in October 2023 is not the last
+        let date = NaiveDate::from_ymd_opt(2023, 10, 24).unwrap();
+        assert!(!is_last_weekday_of_month(&date));
+
+        // Test case 2: The 5th Tuesday is the last
+        let date = NaiveDate::from_ymd_opt(2023, 10, 31).unwrap();
+        assert!(is_last_weekday_of_month(&date));
+
+        // Test case 3: The 4th of 4 Fridays is the last
+        let date = NaiveDate::from_ymd_opt(2023, 10, 27).unwrap();
+        assert!(is_last_weekday_of_month(&date));
+
+        // Test case 4: An early date is not the last
+        let date = NaiveDate::from_ymd_opt(2023, 10, 3).unwrap();
+        assert!(!is_last_weekday_of_month(&date));
+
+        // Test case 5: Agrees with find_last_weekday_of_month for every day of a leap year
+        let mut date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        while date.year() == 2024 {
+            let last = find_last_weekday_of_month(2024, date.month(), date.weekday()).unwrap();
+            assert_eq!(is_last_weekday_of_month(&date), date == last);
+            date = date.succ_opt().unwrap();
+        }
+
+        // Test case 6: The maximum date is the last of its weekday
+        assert!(is_last_weekday_of_month(&NaiveDate::MAX));
+    }
+
+    #[test]
+    fn test_days_forward_to() {
+        let weekdays = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+
+        // Expected days forward, indexed by [from][target] starting from Monday.
+        let expected = [
+            [7, 1, 2, 3, 4, 5, 6],
+            [6, 7, 1, 2, 3, 4, 5],
+            [5, 6, 7, 1, 2, 3, 4],
+            [4, 5, 6, 7, 1, 2, 3],
+            [3, 4, 5, 6, 7, 1, 2],
+            [2, 3, 4, 5, 6, 7, 1],
+            [1, 2, 3, 4, 5, 6, 7],
+        ];
+
+        for (i, from) in weekdays.iter().enumerate() {
+            for (j, target) in weekdays.iter().enumerate() {
+                assert_eq!(
+                    days_forward_to(*from, *target),
+                    expected[i][j],
+                    "from {from} to {target}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_nth_weekday_of_month() {
+        // Test case 1: The 1st occurrence is the 1st of the month
+        let result = find_nth_weekday_of_month(2023, 10, Weekday::Sun, 1).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 1).unwrap());
+
+        // Test case 2: The 2nd occurrence
+        let result = find_nth_weekday_of_month(2023, 10, Weekday::Mon, 2).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 9).unwrap());
+
+        // Test case 3: A 5th occurrence that exists
+        let result = find_nth_weekday_of_month(2023, 10, Weekday::Tue, 5).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 31).unwrap());
+
+        // Test case 4: A 5th occurrence that does not exist
+        assert_eq!(find_nth_weekday_of_month(2023, 10, Weekday::Wed, 5), None);
+
+        // Test case 5: Invalid inputs
+        assert_eq!(find_nth_weekday_of_month(2023, 10, Weekday::Wed, 0), None);
+        assert_eq!(find_nth_weekday_of_month(2023, 0, Weekday::Wed, 1), None);
+    }
+
+    #[test]
+    fn test_find_next_annual_nth_weekday() {
+        // Test case 1: Before this year's 2nd Sunday of May
+        let date = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+        let result = find_next_annual_nth_weekday(&date, 5, Weekday::Sun, 2).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 5, 12).unwrap());
+
+        // Test case 2: On this year's occurrence, so it returns next year's
+        let date = NaiveDate::from_ymd_opt(2024, 5, 12).unwrap();
+        let result = find_next_annual_nth_weekday(&date, 5, Weekday::Sun, 2).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2025, 5, 11).unwrap());
+
+        // Test case 3: After this year's occurrence
+        let date = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+        let result = find_next_annual_nth_weekday(&date, 5, Weekday::Sun, 2).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2025, 5, 11).unwrap());
+
+        // Test case 4: A 5th occurrence skips years where the month only has four
+        let date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let result = find_next_annual_nth_weekday(&date, 2, Weekday::Thu, 5).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+
+        // Test case 5: An occurrence that never exists
+        assert_eq!(
+            find_next_annual_nth_weekday(&date, 5, Weekday::Sun, 6),
+            None
+        );
+
+        // Test case 6: A 5th Sunday of February 40 years away, across the non-leap year 2100
+        for year in [2088, 2090, 2095] {
+            let date = NaiveDate::from_ymd_opt(year, 3, 1).unwrap();
+            let result = find_next_annual_nth_weekday(&date, 2, Weekday::Sun, 5).unwrap();
+            assert_eq!(result, NaiveDate::from_ymd_opt(2128, 2, 29).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_find_next_annual_last_weekday() {
+        // Test case 1: Before this year's last Monday of May
+        let date = NaiveDate::from_ymd_opt(2024, 5, 20).unwrap();
+        let result = find_next_annual_last_weekday(&date, 5, Weekday::Mon).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 5, 27).unwrap());
+
+        // Test case 2: On this year's occurrence, so it returns next year's
+        let date = NaiveDate::from_ymd_opt(2024, 5, 27).unwrap();
+        let result = find_next_annual_last_weekday(&date, 5, Weekday::Mon).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2025, 5, 26).unwrap());
+
+        // Test case 3: After this year's occurrence, crossing into next year
+        let date = NaiveDate::from_ymd_opt(2024, 11, 30).unwrap();
+        let result = find_next_annual_last_weekday(&date, 5, Weekday::Mon).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2025, 5, 26).unwrap());
+
+        // Test case 4: Last Friday of December from between the last two Fridays
+        let date = NaiveDate::from_ymd_opt(2023, 12, 25).unwrap();
+        let result = find_next_annual_last_weekday(&date, 12, Weekday::Fri).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 12, 29).unwrap());
+
+        // Test case 5: Invalid month
+        assert_eq!(find_next_annual_last_weekday(&date, 13, Weekday::Fri), None);
+    }
+
+    #[test]
+    fn test_find_next_annual_date_with_year_count() {
+        let origin = NaiveDate::from_ymd_opt(2015, 6, 20).unwrap();
+
+        // Test case 1: Anniversary later this year
+        let date = NaiveDate::from_ymd_opt(2023, 5, 1).unwrap();
+        let result = find_next_annual_date_with_year_count(&date, &origin).unwrap();
+        assert_eq!(result, (NaiveDate::from_ymd_opt(2023, 6, 20).unwrap(), 8));
+
+        // Test case 2: On the anniversary, so it returns next year's
+        let date = NaiveDate::from_ymd_opt(2023, 6, 20).unwrap();
+        let result = find_next_annual_date_with_year_count(&date, &origin).unwrap();
+        assert_eq!(result, (NaiveDate::from_ymd_opt(2024, 6, 20).unwrap(), 9));
+
+        // Test case 3: On the origin date, the first anniversary is one year later
+        let result = find_next_annual_date_with_year_count(&origin, &origin).unwrap();
+        assert_eq!(result, (NaiveDate::from_ymd_opt(2016, 6, 20).unwrap(), 1));
+
+        // Test case 4: Before the origin, the origin year is excluded
+        let date = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap();
+        let result = find_next_annual_date_with_year_count(&date, &origin).unwrap();
+        assert_eq!(result, (NaiveDate::from_ymd_opt(2016, 6, 20).unwrap(), 1));
+
+        // Test case 5: A February 29 origin reaches its next leap anniversary
+        let origin = NaiveDate::from_ymd_opt(2020, 2, 29).unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let result = find_next_annual_date_with_year_count(&date, &origin).unwrap();
+        assert_eq!(result, (NaiveDate::from_ymd_opt(2028, 2, 29).unwrap(), 8));
+    }
+
+    #[test]
+    fn test_find_next_weekday_saturating() {
+        // Test case 1: Away from the limits, it agrees with find_next_weekday
+        let date = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(); // Monday
+        let result = find_next_weekday_saturating(&date, Weekday::Mon);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 23).unwrap());
+
+        // Test case 2: Near the maximum date, a matching date still fits
+        let max_weekday = NaiveDate::MAX.weekday();
+        let date = NaiveDate::MAX - Days::new(3);
+        let result = find_next_weekday_saturating(&date, max_weekday);
+        assert_eq!(result, NaiveDate::MAX);
+
+        // Test case 3: On the maximum date, it saturates to the latest matching date
+        for weekday in [Weekday::Mon, Weekday::Thu, Weekday::Sun] {
+            let result = find_next_weekday_saturating(&NaiveDate::MAX, weekday);
+            assert_eq!(result.weekday(), weekday);
+            assert!(NaiveDate::MAX - result < chrono::TimeDelta::days(7));
+            assert_eq!(find_next_weekday(&NaiveDate::MAX, weekday), None);
+        }
+
+        // Test case 4: Saturating on the maximum date's own weekday returns the maximum date
+        let result = find_next_weekday_saturating(&NaiveDate::MAX, max_weekday);
+        assert_eq!(result, NaiveDate::MAX);
+    }
+
+    #[test]
+    fn test_find_next_every_n_days() {
+        let anchor = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+
+        // Test case 1: Between grid points
+        let date = NaiveDate::from_ymd_opt(2023, 1, 15).unwrap();
+        let result = find_next_every_n_days(&anchor, &date, 10).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 1, 21).unwrap());
+
+        // Test case 2: Exactly on a grid point, so it returns the next one
+        let date = NaiveDate::from_ymd_opt(2023, 1, 21).unwrap();
+        let result = find_next_every_n_days(&anchor, &date, 10).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 1, 31).unwrap());
+
+        // Test case 3: On the anchor
+        let result = find_next_every_n_days(&anchor, &anchor, 10).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 1, 11).unwrap());
+
+        // Test case 4: Before the anchor, the anchor is the next grid point
+        let date = NaiveDate::from_ymd_opt(2022, 12, 1).unwrap();
+        let result = find_next_every_n_days(&anchor, &date, 10).unwrap();
+        assert_eq!(result, anchor);
+
+        // Test case 5: A step of one day is the next day
+        let date = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
+        let result = find_next_every_n_days(&anchor, &date, 1).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+
+        // Test case 6: A step of zero days is rejected
+        assert_eq!(find_next_every_n_days(&anchor, &date, 0), None);
+    }
+
+    #[test]
+    fn test_find_next_always_advances() {
+        // Every finder fed its own result must move strictly forward, so that a
+        // `date = next(date)` loop never stalls.
+        fn assert_advances(
+            name: &str,
+            start: NaiveDate,
+            f: impl Fn(&NaiveDate) -> Option<NaiveDate>,
+        ) {
+            let first = f(&start).unwrap();
+            let second = f(&first).unwrap();
+            assert!(first > start, "{name} from {start}");
+            assert!(second > first, "{name} from {first}");
+        }
+
+        let anchor = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let mut start = NaiveDate::from_ymd_opt(2023, 12, 1).unwrap();
+        while start <= NaiveDate::from_ymd_opt(2025, 3, 31).unwrap() {
+            for weekday in [Weekday::Mon, Weekday::Thu, Weekday::Sun] {
+                assert_advances("find_next_weekday", start, |d| {
+                    find_next_weekday(d, weekday)
+                });
+                assert_advances("find_next_weekday_crossing", start, |d| {
+                    find_next_weekday_crossing(d, weekday).map(|(date, _, _)| date)
+                });
+                assert_advances("find_next_weekday_saturating", start, |d| {
+                    Some(find_next_weekday_saturating(d, weekday))
+                });
+                assert_advances("find_next_annual_nth_weekday", start, |d| {
+                    find_next_annual_nth_weekday(d, 2, weekday, 5)
+                });
+                assert_advances("find_next_annual_last_weekday", start, |d| {
+                    find_next_annual_last_weekday(d, 2, weekday)
+                });
+            }
+            for day in [1, 15, 29, 30, 31] {
+                assert_advances("find_next_day_of_month", start, |d| {
+                    find_next_day_of_month(d, day)
+                });
+                for rule in [WeekendRule::Preceding, WeekendRule::Following] {
+                    assert_advances("find_next_day_of_month_adjusted", start, |d| {
+                        find_next_day_of_month_adjusted(d, day, rule)
+                    });
+                }
+            }
+            for (month, day) in [(1, 1), (2, 29), (12, 31)] {
+                assert_advances("find_next_annual_date", start, |d| {
+                    find_next_annual_date(d, month, day)
+                });
+            }
+            assert_advances("find_next_from_annual_set", start, |d| {
+                find_next_from_annual_set(d, &[(2, 29), (8, 31)])
+            });
+            assert_advances("find_next_annual_date_with_year_count", start, |d| {
+                find_next_annual_date_with_year_count(d, &anchor).map(|(date, _)| date)
+            });
+            assert_advances("find_next_same_weekday_with_day_parity", start, |d| {
+                find_next_same_weekday_with_day_parity(d, true)
+            });
+            assert_advances("find_next_every_n_days", start, |d| {
+                find_next_every_n_days(&anchor, d, 10)
+            });
+
+            start = start.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_find_next_day_of_month_from_ym() {
+        // Test case 1: The day exists in the given month
+        let result = find_next_day_of_month_from_ym(2023, 10, 15).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 15).unwrap());
+
+        // Test case 2: The 1st of the given month is included
+        let result = find_next_day_of_month_from_ym(2023, 10, 1).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 1).unwrap());
+
+        // Test case 3: Day 31 from February skips to March
+        let result = find_next_day_of_month_from_ym(2023, 2, 31).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 3, 31).unwrap());
+
+        // Test case 4: Day 30 from February skips to March, even in a leap year
+        let result = find_next_day_of_month_from_ym(2024, 2, 30).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 3, 30).unwrap());
+
+        // Test case 5: Day 29 from February in a leap year stays in February
+        let result = find_next_day_of_month_from_ym(2024, 2, 29).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+
+        // Test case 6: Invalid month or day
+        assert_eq!(find_next_day_of_month_from_ym(2023, 13, 1), None);
+        assert_eq!(find_next_day_of_month_from_ym(2023, 1, 0), None);
+        assert_eq!(find_next_day_of_month_from_ym(2023, 1, 32), None);
+    }
+
+    #[test]
+    fn test_days_in_month() {
+        // Test case 1: Every month of a non-leap year
+        let expected = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+        for (month, days) in (1..=12).zip(expected) {
+            assert_eq!(days_in_month(2023, month), Some(days));
+        }
+
+        // Test case 2: Every month of a leap year
+        let expected = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+        for (month, days) in (1..=12).zip(expected) {
+            assert_eq!(days_in_month(2024, month), Some(days));
+        }
+
+        // Test case 3: Century years are only leap years when divisible by 400
+        assert_eq!(days_in_month(1900, 2), Some(28));
+        assert_eq!(days_in_month(2000, 2), Some(29));
+
+        // Test case 4: Invalid months
+        assert_eq!(days_in_month(2023, 0), None);
+        assert_eq!(days_in_month(2023, 13), None);
+    }
+
+    #[test]
+    fn test_find_next_day_of_month_plus_business_days() {
+        let date = NaiveDate::from_ymd_opt(2023, 11, 15).unwrap();
+
+        // Test case 1: The anchor is a Friday and the offset crosses the weekend
+        let result = find_next_day_of_month_plus_business_days(&date, 1, 1, &NoHolidays);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 12, 4));
+        let result = find_next_day_of_month_plus_business_days(&date, 1, 2, &NoHolidays);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 12, 5));
+
+        // Test case 2: A holiday after the weekend is skipped as well
+        let holidays = [NaiveDate::from_ymd_opt(2023, 12, 4).unwrap()];
+        let result = find_next_day_of_month_plus_business_days(&date, 1, 2, &holidays);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 12, 6));
+
+        // Test case 3: An offset of zero is the anchor itself
+        let result = find_next_day_of_month_plus_business_days(&date, 1, 0, &NoHolidays);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 12, 1));
+
+        // Test case 4: Invalid day
+        let result = find_next_day_of_month_plus_business_days(&date, 32, 2, &NoHolidays);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_find_next_weekday_in_set_contiguous() {
+        let date = |d| NaiveDate::from_ymd_opt(2023, 10, d).unwrap(); // October 16th is a Monday
+
+        // Test case 1: Non-wrapping range, inside and at the end of it
+        let result = find_next_weekday_in_set_contiguous(&date(16), Weekday::Mon, Weekday::Thu);
+        assert_eq!(result, Some(date(17)));
+        let result = find_next_weekday_in_set_contiguous(&date(19), Weekday::Mon, Weekday::Thu);
+        assert_eq!(result, Some(date(23)));
+        let result = find_next_weekday_in_set_contiguous(&date(21), Weekday::Mon, Weekday::Thu);
+        assert_eq!(result, Some(date(23)));
+
+        // Test case 2: Wrapping range
+        let result = find_next_weekday_in_set_contiguous(&date(17), Weekday::Sat, Weekday::Tue);
+        assert_eq!(result, Some(date(21)));
+        let result = find_next_weekday_in_set_contiguous(&date(22), Weekday::Sat, Weekday::Tue);
+        assert_eq!(result, Some(date(23)));
+        let result = find_next_weekday_in_set_contiguous(&date(24), Weekday::Sat, Weekday::Tue);
+        assert_eq!(result, Some(date(28)));
+
+        // Test case 3: A single-day range is the same as find_next_weekday
+        let result = find_next_weekday_in_set_contiguous(&date(16), Weekday::Mon, Weekday::Mon);
+        assert_eq!(result, find_next_weekday(&date(16), Weekday::Mon));
+    }
+
+    #[test]
+    fn test_results_are_reproducible() {
+        use chrono::{FixedOffset, TimeZone, Utc};
+
+        // Test case 1: Golden values, which must never depend on when or where the tests run
+        let date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
+        assert_eq!(
+            find_next_weekday(&date, Weekday::Mon),
+            NaiveDate::from_ymd_opt(2023, 10, 16)
+        );
+        assert_eq!(
+            find_next_day_of_month(&date, 31),
+            NaiveDate::from_ymd_opt(2023, 10, 31)
+        );
+        assert_eq!(
+            find_next_annual_date(&date, 2, 29),
+            NaiveDate::from_ymd_opt(2024, 2, 29)
+        );
+        assert_eq!(
+            Recurrence::DayOfMonth(15).next(&date),
+            NaiveDate::from_ymd_opt(2023, 11, 15)
+        );
+        assert_eq!(
+            QueryContext::new(date).next_weekday(Weekday::Sun),
+            NaiveDate::from_ymd_opt(2023, 10, 22)
+        );
+
+        // Test case 2: Simulated time zones only matter through the date they inject.
+        // 23:00 UTC on October 31st is still October 31st at UTC-10 but already November 1st
+        // at UTC+14, and each date gives the same results whichever zone produced it.
+        let now = Utc.with_ymd_and_hms(2023, 10, 31, 23, 0, 0).unwrap();
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+        let cases = [
+            (-10, date(10, 31), date(11, 1)),
+            (0, date(10, 31), date(11, 1)),
+            (14, date(11, 1), date(12, 1)),
+        ];
+        for (hours, today, next_first) in cases {
+            let offset = FixedOffset::east_opt(hours * 3600).unwrap();
+            assert_eq!(now.with_timezone(&offset).date_naive(), today);
+            assert_eq!(find_next_day_of_month(&today, 1), Some(next_first));
+            assert_eq!(find_next_weekday(&today, Weekday::Mon), Some(date(11, 6)));
+        }
+    }
+
+    #[test]
+    fn test_try_find_next_day_of_month() {
+        // Test case 1: Agrees with find_next_day_of_month, and valid days never exhaust the window
+        let mut date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        while date <= NaiveDate::from_ymd_opt(2024, 12, 31).unwrap() {
+            for day in 1..=31 {
+                let result = try_find_next_day_of_month(&date, day);
+                assert_eq!(result.ok(), find_next_day_of_month(&date, day));
+                assert!(result.is_ok());
+            }
+            date = date.succ_opt().unwrap();
+        }
+
+        // Test case 2: Invalid days
+        let date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
+        assert_eq!(
+            try_find_next_day_of_month(&date, 0),
+            Err(NextDayError::InvalidDay(0))
+        );
+        assert_eq!(
+            try_find_next_day_of_month(&date, 32),
+            Err(NextDayError::InvalidDay(32))
+        );
+
+        // Test case 3: A valid day with no room left in the date range
+        assert_eq!(
+            try_find_next_day_of_month(&NaiveDate::MAX, 31),
+            Err(NextDayError::NotFoundWithinWindow)
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_find_next_weekday_by_value() {
+        let weekdays = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+
+        // Test case 1: The by-value and by-reference forms always agree
+        let mut date = NaiveDate::from_ymd_opt(2023, 12, 25).unwrap();
+        for _ in 0..14 {
+            for weekday in weekdays {
+                let result = find_next_weekday(&date, weekday).unwrap();
+                assert_eq!(Some(result), find_next_weekday_ref(&date, &weekday));
+                assert_eq!(result.weekday(), weekday);
+                assert!((1..=7).contains(&(result - date).num_days()));
+            }
+            date = date.succ_opt().unwrap();
+        }
+
+        // Test case 2: A by-value call on the same weekday moves a full week
+        let date = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(); // Monday
+        let result = find_next_weekday(&date, Weekday::Mon).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 23).unwrap());
+    }
+
+    #[test]
+    fn test_find_next_quarter_start() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: Calendar quarters
+        assert_eq!(
+            find_next_quarter_start(&date(2023, 10, 15), 1),
+            Some(date(2024, 1, 1))
+        );
+        assert_eq!(
+            find_next_quarter_start(&date(2023, 3, 31), 1),
+            Some(date(2023, 4, 1))
+        );
+
+        // Test case 2: On a quarter start, the next one is returned
+        assert_eq!(
+            find_next_quarter_start(&date(2023, 4, 1), 1),
+            Some(date(2023, 7, 1))
+        );
+
+        // Test case 3: A fiscal year starting in April or November
+        assert_eq!(
+            find_next_quarter_start(&date(2023, 2, 10), 4),
+            Some(date(2023, 4, 1))
+        );
+        assert_eq!(
+            find_next_quarter_start(&date(2023, 12, 10), 11),
+            Some(date(2024, 2, 1))
+        );
+
+        // Test case 4: Invalid month
+        assert_eq!(find_next_quarter_start(&date(2023, 2, 10), 0), None);
+        assert_eq!(find_next_quarter_start(&date(2023, 2, 10), 13), None);
+    }
+
+    #[test]
+    fn test_nearest_quarter_boundary() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: Clearly closer to the previous boundary
+        assert_eq!(
+            nearest_quarter_boundary(&date(2023, 4, 10), 1),
+            Some(date(2023, 4, 1))
+        );
+
+        // Test case 2: Clearly closer to the next boundary
+        assert_eq!(
+            nearest_quarter_boundary(&date(2023, 6, 20), 1),
+            Some(date(2023, 7, 1))
+        );
+
+        // Test case 3: Equidistant between two boundaries, so the previous one wins.
+        // October 1st to January 1st is 92 days, and November 16th is 46 days from both.
+        assert_eq!(
+            date(2023, 11, 16) - date(2023, 10, 1),
+            date(2024, 1, 1) - date(2023, 11, 16)
+        );
+        assert_eq!(
+            nearest_quarter_boundary(&date(2023, 11, 16), 1),
+            Some(date(2023, 10, 1))
+        );
+        assert_eq!(
+            nearest_quarter_boundary(&date(2023, 11, 17), 1),
+            Some(date(2024, 1, 1))
+        );
+
+        // Test case 4: A quarter start is returned unchanged
+        assert_eq!(
+            nearest_quarter_boundary(&date(2023, 7, 1), 1),
+            Some(date(2023, 7, 1))
+        );
+
+        // Test case 5: A fiscal year starting in February
+        assert_eq!(
+            nearest_quarter_boundary(&date(2024, 1, 20), 2),
+            Some(date(2024, 2, 1))
+        );
+
+        // Test case 6: An invalid fiscal start month
+        assert_eq!(nearest_quarter_boundary(&date(2023, 5, 20), 0), None);
+        assert_eq!(nearest_quarter_boundary(&date(2023, 5, 20), 13), None);
+
+        // Test case 7: Before the first representable quarter start
+        let early = NaiveDate::MIN + Days::new(10);
+        assert_eq!(
+            nearest_quarter_boundary(&early, 2),
+            NaiveDate::from_ymd_opt(NaiveDate::MIN.year(), 2, 1)
+        );
+        assert_eq!(nearest_quarter_boundary(&early, 1), Some(NaiveDate::MIN));
+    }
+
+    #[test]
+    fn test_find_next_friday_the_13th() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: Just before a Friday the 13th
+        assert_eq!(
+            find_next_friday_the_13th(&date(2023, 10, 12)),
+            Some(date(2023, 10, 13))
+        );
+
+        // Test case 2: On and just after a Friday the 13th, the next one is months away
+        assert_eq!(
+            find_next_friday_the_13th(&date(2023, 10, 13)),
+            Some(date(2024, 9, 13))
+        );
+        assert_eq!(
+            find_next_friday_the_13th(&date(2023, 10, 14)),
+            Some(date(2024, 9, 13))
+        );
+
+        // Test case 3: The longest gap of 14 months
+        assert_eq!(
+            find_next_friday_the_13th(&date(2001, 7, 13)),
+            Some(date(2002, 9, 13))
+        );
+
+        // Test case 4: Never None when chained across a full 400-year Gregorian cycle
+        let mut current = date(2000, 1, 1);
+        while current.year() < 2400 {
+            let result = find_next_friday_the_13th(&current).unwrap();
+            assert_eq!((result.day(), result.weekday()), (13, Weekday::Fri));
+            assert!(result > current);
+            current = result;
+        }
+    }
+
+    #[test]
+    fn test_find_next_matching() {
+        let date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
+
+        // Test case 1: A predicate that matches within the bound
+        let result = find_next_matching(&date, 31, |d| d.day() == 1);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 1));
+
+        // Test case 2: The start date itself is never checked
+        let result = find_next_matching(&date, 31, |d| d.day() == 15);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 15));
+
+        // Test case 3: The last day of the bound is still checked
+        let result = find_next_matching(&date, 17, |d| d.day() == 1);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 1));
+        assert_eq!(find_next_matching(&date, 16, |d| d.day() == 1), None);
+
+        // Test case 4: A predicate that never matches stops after exactly max_days checks
+        let mut checked = 0;
+        let result = find_next_matching(&date, 1000, |_| {
+            checked += 1;
+            false
+        });
+        assert_eq!(result, None);
+        assert_eq!(checked, 1000);
+
+        // Test case 5: A bound of zero checks nothing
+        assert_eq!(find_next_matching(&date, 0, |_| true), None);
+    }
+
+    #[test]
+    fn test_find_next_matching_unbounded() {
+        let date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
+
+        // Test case 1: Agrees with the bounded version
+        let result = find_next_matching_unbounded(&date, |d| d.day() == 31);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 31));
+
+        // Test case 2: A predicate that never matches still returns
+        assert_eq!(find_next_matching_unbounded(&date, |d| d.day() == 32), None);
+    }
+
+    #[test]
+    fn test_find_next_days_before_month_end() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: Zero days before is the month end
+        let result = find_next_days_before_month_end(&date(2023, 1, 15), 0);
+        assert_eq!(result, Some(date(2023, 1, 31)));
+        let result = find_next_days_before_month_end(&date(2023, 1, 31), 0);
+        assert_eq!(result, Some(date(2023, 2, 28)));
+        let result = find_next_days_before_month_end(&date(2024, 2, 28), 0);
+        assert_eq!(result, Some(date(2024, 2, 29)));
+
+        // Test case 2: Three days before, across February in a non-leap and a leap year
+        let result = find_next_days_before_month_end(&date(2023, 1, 28), 3);
+        assert_eq!(result, Some(date(2023, 2, 25)));
+        let result = find_next_days_before_month_end(&date(2023, 2, 25), 3);
+        assert_eq!(result, Some(date(2023, 3, 28)));
+        let result = find_next_days_before_month_end(&date(2024, 2, 1), 3);
+        assert_eq!(result, Some(date(2024, 2, 26)));
+
+        // Test case 3: A 30-day month
+        let result = find_next_days_before_month_end(&date(2023, 4, 1), 3);
+        assert_eq!(result, Some(date(2023, 4, 27)));
+
+        // Test case 4: Months too short are skipped
+        let result = find_next_days_before_month_end(&date(2023, 1, 31), 29);
+        assert_eq!(result, Some(date(2023, 3, 2)));
+
+        // Test case 5: No month is long enough
+        assert_eq!(find_next_days_before_month_end(&date(2023, 1, 1), 31), None);
+    }
+
+    #[test]
+    fn test_same_iso_week() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: Dates in the same week, across the end of the calendar year
+        assert!(same_iso_week(&date(2024, 12, 30), &date(2025, 1, 5)));
+        assert!(same_iso_week(&date(2024, 12, 31), &date(2025, 1, 1)));
+        assert!(same_iso_week(&date(2021, 1, 1), &date(2020, 12, 28)));
+
+        // Test case 2: Adjacent days in different weeks
+        assert!(!same_iso_week(&date(2024, 12, 29), &date(2024, 12, 30)));
+        assert!(!same_iso_week(&date(2025, 1, 5), &date(2025, 1, 6)));
+
+        // Test case 3: The same week number in different ISO years
+        assert!(!same_iso_week(&date(2024, 1, 1), &date(2025, 1, 1)));
+
+        // Test case 4: A date is in its own week
+        assert!(same_iso_week(&date(2023, 10, 15), &date(2023, 10, 15)));
+    }
+
+    #[test]
+    fn test_find_nth_next_weekday() {
+        let date = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(); // Monday
+
+        // Test case 1: The 1st occurrence is the next weekday
+        let result = find_nth_next_weekday(&date, Weekday::Wed, 1);
+        assert_eq!(result, find_next_weekday(&date, Weekday::Wed));
 
-        // Test case 5: Target date is February 29, and the current date is in a non-leap year; returns the next leap year's date
-        let date = NaiveDate::from_ymd_opt(2025, 2, 20).unwrap();
-        let result = find_next_annual_date(&date, 2, 29).unwrap();
-        assert_eq!(result, NaiveDate::from_ymd_opt(2028, 2, 29).unwrap());
+        // Test case 2: The current weekday is not counted
+        let result = find_nth_next_weekday(&date, Weekday::Mon, 2);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 30));
+
+        // Test case 3: n = 0
+        assert_eq!(find_nth_next_weekday(&date, Weekday::Mon, 0), None);
+    }
+
+    #[test]
+    fn test_find_weekday_in_n_weeks() {
+        let monday = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap();
+        let thursday = NaiveDate::from_ymd_opt(2023, 10, 19).unwrap();
+
+        // Test case 1: weeks = 0 stays in the current week, even before the current date
+        let result = find_weekday_in_n_weeks(&thursday, Weekday::Mon, 0);
+        assert_eq!(result, Some(monday));
+
+        // Test case 2: A later weekday differs from the 2nd next occurrence
+        let result = find_weekday_in_n_weeks(&monday, Weekday::Wed, 2);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 1));
+        let result = find_nth_next_weekday(&monday, Weekday::Wed, 2);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 25));
+
+        // Test case 3: On the weekday itself, or an earlier weekday, both agree
+        let result = find_weekday_in_n_weeks(&monday, Weekday::Mon, 2);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 30));
+        assert_eq!(result, find_nth_next_weekday(&monday, Weekday::Mon, 2));
+        let result = find_weekday_in_n_weeks(&thursday, Weekday::Tue, 2);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 31));
+        assert_eq!(result, find_nth_next_weekday(&thursday, Weekday::Tue, 2));
+
+        // Test case 4: Sunday ends the week
+        let sunday = NaiveDate::from_ymd_opt(2023, 10, 22).unwrap();
+        let result = find_weekday_in_n_weeks(&sunday, Weekday::Mon, 1);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 23));
+    }
+
+    #[test]
+    fn test_find_next_weekdays_const() {
+        // Test case 1: N = 3 agrees with the weekday iterator
+        let mut date = NaiveDate::from_ymd_opt(2023, 12, 20).unwrap();
+        for _ in 0..14 {
+            for weekday in [Weekday::Mon, Weekday::Thu, Weekday::Sun] {
+                let result: [NaiveDate; 3] = find_next_weekdays_const(&date, weekday).unwrap();
+                let expected: Vec<_> = weekday_iter(date, weekday).take(3).collect();
+                assert_eq!(result.to_vec(), expected);
+            }
+            date = date.succ_opt().unwrap();
+        }
+
+        // Test case 2: N = 0 is an empty array
+        let result: Option<[NaiveDate; 0]> = find_next_weekdays_const(&date, Weekday::Mon);
+        assert_eq!(result, Some([]));
+
+        // Test case 3: Overflow of any of the dates
+        let date = NaiveDate::MAX - Days::new(10);
+        let result: Option<[NaiveDate; 3]> = find_next_weekdays_const(&date, Weekday::Mon);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_biweekly_phase_offset() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap(); // October 16th is a Monday
+
+        // Test case 1: Anchors in the same week are in phase
+        assert_eq!(biweekly_phase_offset(&date(10, 16), &date(10, 22)), 0);
+        assert_eq!(biweekly_phase_offset(&date(10, 20), &date(10, 20)), 0);
+
+        // Test case 2: Anchors one week apart are offset, in either order
+        assert_eq!(biweekly_phase_offset(&date(10, 16), &date(10, 23)), 1);
+        assert_eq!(biweekly_phase_offset(&date(10, 23), &date(10, 16)), 1);
+        assert_eq!(biweekly_phase_offset(&date(10, 22), &date(10, 23)), 1);
+
+        // Test case 3: Anchors two weeks apart are back in phase
+        assert_eq!(biweekly_phase_offset(&date(10, 16), &date(10, 30)), 0);
+        assert_eq!(biweekly_phase_offset(&date(10, 31), &date(10, 18)), 0);
+    }
+
+    #[test]
+    fn test_find_next_day_of_month_avoiding_weekdays() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let weekend = [Weekday::Sat, Weekday::Sun];
+
+        // Test case 1: The next occurrence is not avoided
+        let result = find_next_day_of_month_avoiding_weekdays(&date(2023, 10, 16), 15, &weekend);
+        assert_eq!(result, Some(date(2023, 11, 15)));
+
+        // Test case 2: Consecutive months' 15ths both fall on avoided weekdays.
+        // June 15th, 2024 is a Saturday and July 15th is a Monday.
+        let avoid = [Weekday::Sat, Weekday::Mon];
+        let result = find_next_day_of_month_avoiding_weekdays(&date(2024, 6, 1), 15, &avoid);
+        assert_eq!(result, Some(date(2024, 8, 15)));
+
+        // Test case 3: Skipped months without the day are not counted as avoided.
+        // March 31st, 2024 is a Sunday, and May 31st is a Friday.
+        let result = find_next_day_of_month_avoiding_weekdays(&date(2024, 2, 1), 31, &weekend);
+        assert_eq!(result, Some(date(2024, 5, 31)));
+
+        // Test case 4: No weekdays to avoid is the same as find_next_day_of_month
+        let result = find_next_day_of_month_avoiding_weekdays(&date(2023, 10, 1), 15, &[]);
+        assert_eq!(result, find_next_day_of_month(&date(2023, 10, 1), 15));
+
+        // Test case 5: Every weekday avoided, or an invalid day
+        let all = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+        assert_eq!(
+            find_next_day_of_month_avoiding_weekdays(&date(2023, 10, 1), 15, &all),
+            None
+        );
+        assert_eq!(
+            find_next_day_of_month_avoiding_weekdays(&date(2023, 10, 1), 32, &[]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_next_weekday_clamped() {
+        let date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // Sunday
+        let monday = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap();
+
+        // Test case 1: The next occurrence is exactly on the clamp
+        let result = find_next_weekday_clamped(&date, Weekday::Mon, Some(monday));
+        assert_eq!(result, Some(monday));
+
+        // Test case 2: The next occurrence is just before the clamp
+        let result = find_next_weekday_clamped(&date, Weekday::Mon, monday.succ_opt());
+        assert_eq!(result, Some(monday));
+
+        // Test case 3: The next occurrence is just after the clamp
+        let result = find_next_weekday_clamped(&date, Weekday::Mon, Some(date));
+        assert_eq!(result, None);
+
+        // Test case 4: No clamp
+        let result = find_next_weekday_clamped(&date, Weekday::Mon, None);
+        assert_eq!(result, Some(monday));
+    }
+
+    #[test]
+    fn test_observed_weekday_for_fixed_date() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: July 4th on a Saturday is observed on the Friday before
+        assert_eq!(date(2026, 7, 4).weekday(), Weekday::Sat);
+        assert_eq!(
+            observed_weekday_for_fixed_date(2026, 7, 4),
+            Some(date(2026, 7, 3))
+        );
+
+        // Test case 2: July 4th on a Sunday is observed on the Monday after
+        assert_eq!(date(2027, 7, 4).weekday(), Weekday::Sun);
+        assert_eq!(
+            observed_weekday_for_fixed_date(2027, 7, 4),
+            Some(date(2027, 7, 5))
+        );
+
+        // Test case 3: July 4th on a weekday is observed on the day itself
+        assert_eq!(date(2024, 7, 4).weekday(), Weekday::Thu);
+        assert_eq!(
+            observed_weekday_for_fixed_date(2024, 7, 4),
+            Some(date(2024, 7, 4))
+        );
+
+        // Test case 4: The observed date may be in another year
+        assert_eq!(
+            observed_weekday_for_fixed_date(2022, 1, 1),
+            Some(date(2021, 12, 31))
+        );
+
+        // Test case 5: A date that does not exist
+        assert_eq!(observed_weekday_for_fixed_date(2023, 2, 29), None);
+    }
+
+    #[test]
+    fn test_elapsed_ymd() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: Without borrowing
+        assert_eq!(
+            elapsed_ymd(&date(2020, 8, 10), &date(2023, 10, 15)),
+            Some((3, 2, 5))
+        );
+
+        // Test case 2: Borrowing days from the previous month
+        assert_eq!(
+            elapsed_ymd(&date(2023, 1, 20), &date(2023, 3, 5)),
+            Some((0, 1, 13))
+        );
+
+        // Test case 3: Borrowing across a leap February
+        assert_eq!(
+            elapsed_ymd(&date(2024, 1, 31), &date(2024, 3, 1)),
+            Some((0, 1, 1))
+        );
+        assert_eq!(
+            elapsed_ymd(&date(2024, 1, 30), &date(2024, 3, 1)),
+            Some((0, 1, 1))
+        );
+        assert_eq!(
+            elapsed_ymd(&date(2024, 1, 29), &date(2024, 3, 1)),
+            Some((0, 1, 1))
+        );
+        assert_eq!(
+            elapsed_ymd(&date(2023, 1, 29), &date(2023, 3, 1)),
+            Some((0, 1, 1))
+        );
+        assert_eq!(
+            elapsed_ymd(&date(2024, 1, 31), &date(2024, 2, 29)),
+            Some((0, 1, 0))
+        );
+        assert_eq!(
+            elapsed_ymd(&date(2023, 1, 31), &date(2023, 2, 28)),
+            Some((0, 1, 0))
+        );
+        assert_eq!(
+            elapsed_ymd(&date(2024, 1, 31), &date(2024, 2, 28)),
+            Some((0, 0, 28))
+        );
+
+        // Test case 4: From February 29th
+        assert_eq!(
+            elapsed_ymd(&date(2024, 2, 29), &date(2025, 2, 28)),
+            Some((1, 0, 0))
+        );
+        assert_eq!(
+            elapsed_ymd(&date(2024, 2, 29), &date(2025, 3, 1)),
+            Some((1, 0, 1))
+        );
+        assert_eq!(
+            elapsed_ymd(&date(2024, 2, 29), &date(2028, 2, 29)),
+            Some((4, 0, 0))
+        );
+
+        // Test case 5: Borrowing both months and days across a year boundary
+        assert_eq!(
+            elapsed_ymd(&date(2022, 12, 25), &date(2023, 12, 24)),
+            Some((0, 11, 29))
+        );
+
+        // Test case 6: The same date, and a date before the start
+        assert_eq!(
+            elapsed_ymd(&date(2023, 10, 15), &date(2023, 10, 15)),
+            Some((0, 0, 0))
+        );
+        assert_eq!(elapsed_ymd(&date(2023, 10, 15), &date(2023, 10, 14)), None);
+    }
+
+    #[test]
+    fn test_find_next_palindrome_style_nn() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: Between two matching dates
+        assert_eq!(
+            find_next_palindrome_style_nn(&date(2023, 5, 6)),
+            Some(date(2023, 6, 6))
+        );
+        assert_eq!(
+            find_next_palindrome_style_nn(&date(2023, 5, 4)),
+            Some(date(2023, 5, 5))
+        );
+
+        // Test case 2: On a matching date, the next one is returned
+        assert_eq!(
+            find_next_palindrome_style_nn(&date(2023, 2, 2)),
+            Some(date(2023, 3, 3))
+        );
+
+        // Test case 3: After December 12th, the next is January 1st
+        assert_eq!(
+            find_next_palindrome_style_nn(&date(2023, 12, 12)),
+            Some(date(2024, 1, 1))
+        );
+        assert_eq!(
+            find_next_palindrome_style_nn(&date(2023, 12, 31)),
+            Some(date(2024, 1, 1))
+        );
+
+        // Test case 4: All twelve dates of a year, in order
+        let mut current = date(2023, 12, 31);
+        for month in 1..=12 {
+            current = find_next_palindrome_style_nn(&current).unwrap();
+            assert_eq!(current, date(2024, month, month));
+        }
+    }
+
+    #[test]
+    fn test_stepped_weekdays_in_range() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let start = date(2023, 10, 1);
+        let end = date(2023, 12, 31);
+
+        // Test case 1: A step of one week lists every matching weekday
+        let result = stepped_weekdays_in_range(&start, &end, Weekday::Tue, 1);
+        assert_eq!(result.len(), 13);
+        assert!(result.iter().all(|d| d.weekday() == Weekday::Tue));
+        assert!(result
+            .windows(2)
+            .all(|w| w[1] - w[0] == chrono::TimeDelta::days(7)));
+
+        // Test case 2: A step of three weeks over several months
+        let result = stepped_weekdays_in_range(&start, &end, Weekday::Tue, 3);
+        assert_eq!(
+            result,
+            vec![
+                date(2023, 10, 3),
+                date(2023, 10, 24),
+                date(2023, 11, 14),
+                date(2023, 12, 5),
+                date(2023, 12, 26),
+            ]
+        );
+
+        // Test case 3: The start and end dates are both included
+        let result =
+            stepped_weekdays_in_range(&date(2023, 10, 3), &date(2023, 10, 24), Weekday::Tue, 3);
+        assert_eq!(result, vec![date(2023, 10, 3), date(2023, 10, 24)]);
+
+        // Test case 4: A step of zero or an empty range
+        assert!(stepped_weekdays_in_range(&start, &end, Weekday::Tue, 0).is_empty());
+        assert!(stepped_weekdays_in_range(&end, &start, Weekday::Tue, 1).is_empty());
+    }
+
+    #[test]
+    fn test_find_next_weekday_with_week_parity() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+        let reference = date(10, 4); // Wednesday of week 0, which starts on Monday, October 2nd
+
+        // Test case 1: The next Tuesday is in week 0, which is even
+        let result =
+            find_next_weekday_with_week_parity(&reference, &date(10, 2), Weekday::Tue, false);
+        assert_eq!(result, Some(date(10, 3)));
+
+        // Test case 2: Parity does not match, so it skips to the week after
+        let result =
+            find_next_weekday_with_week_parity(&reference, &date(10, 2), Weekday::Tue, true);
+        assert_eq!(result, Some(date(10, 10)));
+        let result =
+            find_next_weekday_with_week_parity(&reference, &date(10, 10), Weekday::Tue, true);
+        assert_eq!(result, Some(date(10, 24)));
+
+        // Test case 3: Weeks before the reference keep alternating
+        let result =
+            find_next_weekday_with_week_parity(&reference, &date(9, 20), Weekday::Mon, true);
+        assert_eq!(result, Some(date(9, 25)));
+        let result =
+            find_next_weekday_with_week_parity(&reference, &date(9, 20), Weekday::Mon, false);
+        assert_eq!(result, Some(date(10, 2)));
+    }
+
+    #[test]
+    fn test_validate_ymd() {
+        // Test case 1: Valid dates, including February 29th in a leap year
+        for (y, m, d) in [(2023, 1, 1), (2023, 12, 31), (2024, 2, 29), (2000, 2, 29)] {
+            assert_eq!(
+                validate_ymd(y, m, d),
+                Ok(NaiveDate::from_ymd_opt(y, m, d).unwrap())
+            );
+        }
+
+        // Test case 2: Invalid months
+        assert_eq!(validate_ymd(2023, 0, 1), Err(NextDayError::InvalidMonth(0)));
+        assert_eq!(
+            validate_ymd(2023, 13, 1),
+            Err(NextDayError::InvalidMonth(13))
+        );
+
+        // Test case 3: Days the month never has
+        assert_eq!(validate_ymd(2024, 2, 30), Err(NextDayError::InvalidDay(30)));
+        assert_eq!(validate_ymd(2023, 4, 31), Err(NextDayError::InvalidDay(31)));
+        assert_eq!(validate_ymd(2023, 1, 0), Err(NextDayError::InvalidDay(0)));
+        assert_eq!(validate_ymd(2023, 1, 32), Err(NextDayError::InvalidDay(32)));
+
+        // Test case 4: February 29th in non-leap years
+        assert_eq!(validate_ymd(2023, 2, 29), Err(NextDayError::InvalidDay(29)));
+        assert_eq!(validate_ymd(1900, 2, 29), Err(NextDayError::InvalidDay(29)));
+
+        // Test case 5: Years out of range
+        assert_eq!(
+            validate_ymd(i32::MAX, 1, 1),
+            Err(NextDayError::InvalidYear(i32::MAX))
+        );
+    }
+
+    #[test]
+    fn test_find_next_weekday_after_days() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+        let monday = date(10, 16);
+
+        // Test case 1: The earliest allowed date is exactly on the weekday
+        let result = find_next_weekday_after_days(&monday, Weekday::Mon, 7);
+        assert_eq!(result, Some(date(10, 23)));
+        let result = find_next_weekday_after_days(&monday, Weekday::Fri, 4);
+        assert_eq!(result, Some(date(10, 20)));
+
+        // Test case 2: The earliest allowed date is past the weekday
+        let result = find_next_weekday_after_days(&monday, Weekday::Fri, 5);
+        assert_eq!(result, Some(date(10, 27)));
+
+        // Test case 3: No minimum can return the current date
+        let result = find_next_weekday_after_days(&monday, Weekday::Mon, 0);
+        assert_eq!(result, Some(monday));
+
+        // Test case 4: Overflow
+        let result = find_next_weekday_after_days(&monday, Weekday::Mon, u64::MAX);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_from_timestamp() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: The epoch and the last second of its day
+        assert_eq!(from_timestamp(0), Some(date(1970, 1, 1)));
+        assert_eq!(from_timestamp(86_399), Some(date(1970, 1, 1)));
+        assert_eq!(from_timestamp(86_400), Some(date(1970, 1, 2)));
+
+        // Test case 2: A known date, at midnight and late in the day
+        assert_eq!(from_timestamp(1_697_328_000), Some(date(2023, 10, 15)));
+        assert_eq!(from_timestamp(1_697_414_399), Some(date(2023, 10, 15)));
+
+        // Test case 3: Negative timestamps are before 1970
+        assert_eq!(from_timestamp(-1), Some(date(1969, 12, 31)));
+        assert_eq!(from_timestamp(-86_400), Some(date(1969, 12, 31)));
+        assert_eq!(from_timestamp(-86_401), Some(date(1969, 12, 30)));
+        assert_eq!(from_timestamp(-2_208_988_800), Some(date(1900, 1, 1)));
+
+        // Test case 4: Agrees with chrono's own conversion
+        for secs in [-10_000_000_000, -1, 0, 951_782_400, 4_102_444_799] {
+            let expected = chrono::DateTime::from_timestamp(secs, 0).map(|dt| dt.date_naive());
+            assert_eq!(from_timestamp(secs), expected);
+        }
+
+        // Test case 5: Out of range
+        assert_eq!(from_timestamp(i64::MAX), None);
+        assert_eq!(from_timestamp(i64::MIN), None);
+    }
+
+    #[test]
+    fn test_weekdays_occurring_five_times() {
+        // Test case 1: A 31-day month has three, starting from the weekday of the 1st
+        let result = weekdays_occurring_five_times(2023, 12).unwrap(); // Starts on a Friday
+        assert_eq!(result, vec![Weekday::Fri, Weekday::Sat, Weekday::Sun]);
+        for weekday in result {
+            let count = (1..=31)
+                .filter(|&d| NaiveDate::from_ymd_opt(2023, 12, d).unwrap().weekday() == weekday)
+                .count();
+            assert_eq!(count, 5);
+        }
+
+        // Test case 2: Wrapping around the end of the week
+        let result = weekdays_occurring_five_times(2023, 7).unwrap(); // Starts on a Saturday
+        assert_eq!(result, vec![Weekday::Sat, Weekday::Sun, Weekday::Mon]);
+
+        // Test case 3: February has none, or one in a leap year
+        assert_eq!(weekdays_occurring_five_times(2023, 2), Some(vec![]));
+        assert_eq!(
+            weekdays_occurring_five_times(2024, 2),
+            Some(vec![Weekday::Thu])
+        );
+
+        // Test case 4: Invalid month
+        assert_eq!(weekdays_occurring_five_times(2023, 13), None);
+    }
+
+    #[test]
+    fn test_find_nth_weekday_from_month_end() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+
+        // Test case 1: n = 1 is the last weekday of the month
+        for month in 1..=12 {
+            for weekday in [Weekday::Mon, Weekday::Fri, Weekday::Sun] {
+                assert_eq!(
+                    find_nth_weekday_from_month_end(2023, month, weekday, 1),
+                    find_last_weekday_of_month(2023, month, weekday)
+                );
+            }
+        }
+
+        // Test case 2: n = 2 in a month with only four occurrences.
+        // November 2023 has four Mondays: the 6th, 13th, 20th and 27th.
+        let result = find_nth_weekday_from_month_end(2023, 11, Weekday::Mon, 2);
+        assert_eq!(result, Some(date(11, 20)));
+        let result = find_nth_weekday_from_month_end(2023, 11, Weekday::Mon, 4);
+        assert_eq!(result, Some(date(11, 6)));
+
+        // Test case 3: More occurrences than the month has
+        assert_eq!(
+            find_nth_weekday_from_month_end(2023, 11, Weekday::Mon, 5),
+            None
+        );
+
+        // Test case 4: n = 0 or an invalid month
+        assert_eq!(
+            find_nth_weekday_from_month_end(2023, 11, Weekday::Mon, 0),
+            None
+        );
+        assert_eq!(
+            find_nth_weekday_from_month_end(2023, 13, Weekday::Mon, 1),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_next_weekday_in_iso_week_parity() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: The nearest weekday is already in a week of the right parity
+        let current = date(2023, 10, 14); // Saturday of ISO week 41
+        let result = find_next_weekday_in_iso_week_parity(&current, Weekday::Mon, true);
+        assert_eq!(result, Some(date(2023, 10, 16))); // Week 42
+
+        // Test case 2: The nearest weekday is in a week of the wrong parity and is skipped
+        let result = find_next_weekday_in_iso_week_parity(&current, Weekday::Mon, false);
+        assert_eq!(result, Some(date(2023, 10, 23))); // Week 43
+
+        // Test case 3: 2020 has 53 ISO weeks, so week 53 and week 1 are both odd
+        let current = date(2020, 12, 21); // Monday of week 52
+        let result = find_next_weekday_in_iso_week_parity(&current, Weekday::Mon, true);
+        assert_eq!(result, Some(date(2021, 1, 11))); // Week 2
+        let result = find_next_weekday_in_iso_week_parity(&current, Weekday::Thu, false);
+        assert_eq!(result, Some(date(2020, 12, 31))); // Week 53
+
+        // Test case 4: Every result has the requested parity
+        let mut current = date(2020, 11, 1);
+        for _ in 0..20 {
+            current = find_next_weekday_in_iso_week_parity(&current, Weekday::Fri, true).unwrap();
+            assert_eq!(current.weekday(), Weekday::Fri);
+            assert_eq!(current.iso_week().week() % 2, 0);
+        }
+    }
+
+    #[test]
+    fn test_dates_in_range() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+
+        // Test case 1: Both ends are included
+        let dates: Vec<_> = dates_in_range(&date(2, 27), &date(3, 2)).collect();
+        assert_eq!(dates, [date(2, 27), date(2, 28), date(3, 1), date(3, 2)]);
+
+        // Test case 2: A single day and a reversed range
+        assert_eq!(dates_in_range(&date(3, 1), &date(3, 1)).count(), 1);
+        assert_eq!(dates_in_range(&date(3, 2), &date(3, 1)).count(), 0);
+
+        // Test case 3: The end of the calendar
+        assert_eq!(dates_in_range(&NaiveDate::MAX, &NaiveDate::MAX).count(), 1);
+    }
+
+    #[test]
+    fn test_find_next_business_day_of_month_anchor() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+
+        // Test case 1: The anchor is already a business day
+        let result = find_next_business_day_of_month_anchor(&date(10, 15), 1, &NoHolidays);
+        assert_eq!(result, Some(date(11, 1)));
+
+        // Test case 2: A Saturday anchor followed by a Monday holiday rolls past both
+        let holidays = [date(7, 3)];
+        let result = find_next_business_day_of_month_anchor(&date(6, 15), 1, &holidays);
+        assert_eq!(result, Some(date(7, 4)));
+        let holidays = [date(7, 3), date(7, 4)];
+        let result = find_next_business_day_of_month_anchor(&date(6, 15), 1, &holidays);
+        assert_eq!(result, Some(date(7, 5)));
+
+        // Test case 3: Rolling forward may cross into the next month
+        let result = find_next_business_day_of_month_anchor(&date(9, 1), 30, &NoHolidays);
+        assert_eq!(result, Some(date(10, 2))); // September 30th is a Saturday
+
+        // Test case 4: An invalid day
+        assert_eq!(
+            find_next_business_day_of_month_anchor(&date(9, 1), 32, &NoHolidays),
+            None
+        );
+    }
+
+    #[test]
+    fn test_weekday_of_next_annual() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: A regular date this year and next year
+        assert_eq!(
+            weekday_of_next_annual(&date(2023, 10, 1), 12, 25),
+            Some(Weekday::Mon)
+        );
+        assert_eq!(
+            weekday_of_next_annual(&date(2023, 12, 25), 12, 25),
+            Some(Weekday::Wed)
+        );
+
+        // Test case 2: February 29th uses the next leap year
+        assert_eq!(
+            weekday_of_next_annual(&date(2023, 3, 1), 2, 29),
+            Some(Weekday::Thu)
+        ); // 2024-02-29
+        assert_eq!(
+            weekday_of_next_annual(&date(2024, 2, 29), 2, 29),
+            Some(Weekday::Tue)
+        ); // 2028-02-29
+
+        // Test case 3: A date that never occurs
+        assert_eq!(weekday_of_next_annual(&date(2023, 1, 1), 2, 30), None);
+    }
+
+    #[test]
+    fn test_countdown_to_next_weekday() {
+        let tuesday = NaiveDate::from_ymd_opt(2023, 10, 17).unwrap();
+
+        // Test case 1: One day away
+        assert_eq!(
+            countdown_to_next_weekday(&tuesday, Weekday::Wed),
+            "Wednesday is tomorrow"
+        );
+
+        // Test case 2: Several days away, including across the weekend
+        assert_eq!(
+            countdown_to_next_weekday(&tuesday, Weekday::Fri),
+            "Friday is in 3 days"
+        );
+        assert_eq!(
+            countdown_to_next_weekday(&tuesday, Weekday::Mon),
+            "Monday is in 6 days"
+        );
+
+        // Test case 3: Today matches, so the next occurrence is a week out
+        assert_eq!(
+            countdown_to_next_weekday(&tuesday, Weekday::Tue),
+            "Tuesday is in 7 days"
+        );
+    }
+
+    #[test]
+    fn test_find_next_day_of_month_not_before_month() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: The natural next occurrence is before the minimum
+        let result = find_next_day_of_month_not_before_month(&date(2024, 1, 3), 15, 3, 2024);
+        assert_eq!(result, Some(date(2024, 3, 15)));
+
+        // Test case 2: The minimum month itself is a candidate, even its 1st
+        let result = find_next_day_of_month_not_before_month(&date(2023, 11, 20), 1, 3, 2024);
+        assert_eq!(result, Some(date(2024, 3, 1)));
+
+        // Test case 3: The minimum month lacks the day, so the search moves on
+        let result = find_next_day_of_month_not_before_month(&date(2024, 1, 3), 31, 4, 2024);
+        assert_eq!(result, Some(date(2024, 5, 31)));
+
+        // Test case 4: The natural next occurrence is already allowed
+        let result = find_next_day_of_month_not_before_month(&date(2024, 5, 3), 15, 3, 2024);
+        assert_eq!(result, Some(date(2024, 5, 15)));
+        let result = find_next_day_of_month_not_before_month(&date(2024, 2, 20), 15, 3, 2024);
+        assert_eq!(result, Some(date(2024, 3, 15)));
+
+        // Test case 5: Invalid day or month
+        let result = find_next_day_of_month_not_before_month(&date(2024, 1, 3), 32, 3, 2024);
+        assert_eq!(result, None);
+        let result = find_next_day_of_month_not_before_month(&date(2024, 1, 3), 15, 13, 2024);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_week_of_month() {
+        let date = |d| NaiveDate::from_ymd_opt(2023, 11, d).unwrap();
+
+        // Test case 1: The 1st through 5th Wednesdays of November 2023
+        for (day, expected) in [(1, 1), (8, 2), (15, 3), (22, 4), (29, 5)] {
+            assert_eq!(date(day).weekday(), Weekday::Wed);
+            assert_eq!(week_of_month(&date(day)), expected);
+        }
+
+        // Test case 2: The boundaries between occurrences
+        assert_eq!(week_of_month(&date(7)), 1);
+        assert_eq!(week_of_month(&date(28)), 4);
+        assert_eq!(week_of_month(&date(30)), 5);
+
+        // Test case 3: The inverse of find_nth_weekday_of_month
+        for day in 1..=30 {
+            let date = date(day);
+            assert_eq!(
+                find_nth_weekday_of_month(2023, 11, date.weekday(), week_of_month(&date)),
+                Some(date)
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_next_weekday_mask() {
+        let date = |d| NaiveDate::from_ymd_opt(2023, 10, d).unwrap();
+        let mon_wed_fri = 0b0010101;
+
+        // Test case 1: Monday, Wednesday and Friday from a Tuesday
+        let mut current = date(17);
+        let mut results = Vec::new();
+        for _ in 0..4 {
+            current = find_next_weekday_mask(&current, mon_wed_fri).unwrap();
+            results.push(current);
+        }
+        assert_eq!(results, [date(18), date(20), date(23), date(25)]);
+
+        // Test case 2: The all-days mask is always the next day
+        for day in 10..=20 {
+            assert_eq!(
+                find_next_weekday_mask(&date(day), 0b1111111),
+                Some(date(day + 1))
+            );
+        }
+
+        // Test case 3: A single bit, including a full week when today matches
+        assert_eq!(find_next_weekday_mask(&date(17), 0b0000010), Some(date(24)));
+        assert_eq!(find_next_weekday_mask(&date(17), 0b1000000), Some(date(22)));
+
+        // Test case 4: A zero mask, with or without the unused high bit
+        assert_eq!(find_next_weekday_mask(&date(17), 0), None);
+        assert_eq!(find_next_weekday_mask(&date(17), 0b10000000), None);
+    }
+
+    #[test]
+    fn test_skipped_months_between() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: Day 31 skips February between January and March
+        let result = skipped_months_between(&date(2023, 1, 31), &date(2023, 3, 31), 31);
+        assert_eq!(result, vec![2]);
+
+        // Test case 2: Day 31 skips the 30-day months, but not July to August
+        let result = skipped_months_between(&date(2023, 3, 31), &date(2023, 5, 31), 31);
+        assert_eq!(result, vec![4]);
+        let result = skipped_months_between(&date(2023, 5, 31), &date(2023, 12, 31), 31);
+        assert_eq!(result, vec![6, 9, 11]);
+        let result = skipped_months_between(&date(2023, 7, 31), &date(2023, 8, 31), 31);
+        assert!(result.is_empty());
+
+        // Test case 3: Day 30 only skips February, across a year boundary
+        let result = skipped_months_between(&date(2022, 11, 30), &date(2024, 3, 30), 30);
+        assert_eq!(result, vec![2, 2]);
+
+        // Test case 4: Day 29 skips February only outside leap years
+        let result = skipped_months_between(&date(2023, 1, 29), &date(2023, 3, 29), 29);
+        assert_eq!(result, vec![2]);
+        let result = skipped_months_between(&date(2024, 1, 29), &date(2024, 3, 29), 29);
+        assert!(result.is_empty());
+
+        // Test case 5: Dates in the same month or out of order
+        let result = skipped_months_between(&date(2023, 1, 1), &date(2023, 1, 31), 31);
+        assert!(result.is_empty());
+        let result = skipped_months_between(&date(2023, 3, 31), &date(2023, 1, 31), 31);
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_find_next_weekday_in_month_half() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+
+        // Test case 1: The nearest weekday is already in the requested half
+        let result = find_next_weekday_in_month_half(&date(10, 2), Weekday::Tue, true);
+        assert_eq!(result, Some(date(10, 3)));
+        let result = find_next_weekday_in_month_half(&date(10, 15), Weekday::Tue, false);
+        assert_eq!(result, Some(date(10, 17)));
+
+        // Test case 2: The nearest weekdays are in the second half, forcing a jump to November
+        let result = find_next_weekday_in_month_half(&date(10, 17), Weekday::Tue, true);
+        assert_eq!(result, Some(date(11, 7)));
+
+        // Test case 3: The nearest weekdays are in the first half, forcing a jump to the 16th
+        let result = find_next_weekday_in_month_half(&date(10, 1), Weekday::Mon, false);
+        assert_eq!(result, Some(date(10, 16)));
+
+        // Test case 4: The 15th and 16th sit on either side of the boundary
+        let result = find_next_weekday_in_month_half(&date(10, 14), Weekday::Sun, true);
+        assert_eq!(result, Some(date(10, 15)));
+        let result = find_next_weekday_in_month_half(&date(10, 14), Weekday::Mon, true);
+        assert_eq!(result, Some(date(11, 6)));
+
+        // Test case 5: Every result is the requested weekday in the requested half
+        for first_half in [true, false] {
+            let mut current = date(1, 1);
+            for _ in 0..52 {
+                let next =
+                    find_next_weekday_in_month_half(&current, Weekday::Fri, first_half).unwrap();
+                assert!(next > current);
+                assert_eq!(next.weekday(), Weekday::Fri);
+                assert_eq!(next.day() <= 15, first_half);
+                current = next;
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_next_annual_date_labeled() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: A regular date later in the same year
+        let result = find_next_annual_date_labeled(&date(2023, 5, 15), 7, 4);
+        assert_eq!(result, Some((date(2023, 7, 4), "July 4, 2023".to_string())));
+
+        // Test case 2: A date that has passed is labeled with the following year
+        let result = find_next_annual_date_labeled(&date(2023, 12, 26), 12, 25);
+        assert_eq!(
+            result,
+            Some((date(2024, 12, 25), "December 25, 2024".to_string()))
+        );
+
+        // Test case 3: February 29th is labeled with the resolved leap year
+        let result = find_next_annual_date_labeled(&date(2024, 3, 1), 2, 29);
+        assert_eq!(
+            result,
+            Some((date(2028, 2, 29), "February 29, 2028".to_string()))
+        );
+        let result = find_next_annual_date_labeled(&date(2097, 1, 1), 2, 29);
+        assert_eq!(
+            result,
+            Some((date(2104, 2, 29), "February 29, 2104".to_string()))
+        );
+
+        // Test case 4: An invalid date has no label
+        assert_eq!(
+            find_next_annual_date_labeled(&date(2023, 1, 1), 4, 31),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_next_prime_day_of_year() {
+        let date = |y, o| NaiveDate::from_yo_opt(y, o).unwrap();
+
+        // Test case 1: The next day of the year is prime
+        assert_eq!(
+            find_next_prime_day_of_year(&date(2023, 1)),
+            Some(date(2023, 2))
+        );
+        assert_eq!(
+            find_next_prime_day_of_year(&date(2023, 2)),
+            Some(date(2023, 3))
+        );
+        assert_eq!(
+            find_next_prime_day_of_year(&date(2023, 30)),
+            Some(date(2023, 31))
+        );
+
+        // Test case 2: Several composite days are skipped
+        assert_eq!(
+            find_next_prime_day_of_year(&date(2023, 32)),
+            Some(date(2023, 37))
+        );
+        assert_eq!(
+            find_next_prime_day_of_year(&date(2023, 113)),
+            Some(date(2023, 127))
+        );
+
+        // Test case 3: The last prime of the year is day 359, after which the search wraps
+        assert_eq!(
+            find_next_prime_day_of_year(&date(2023, 350)),
+            Some(date(2023, 353))
+        );
+        assert_eq!(
+            find_next_prime_day_of_year(&date(2023, 353)),
+            Some(date(2023, 359))
+        );
+        assert_eq!(
+            find_next_prime_day_of_year(&date(2023, 359)),
+            Some(date(2024, 2))
+        );
+        assert_eq!(
+            find_next_prime_day_of_year(&date(2024, 366)),
+            Some(date(2025, 2))
+        );
+
+        // Test case 4: The primality check
+        let primes: Vec<_> = (0..30).filter(|&n| is_prime(n)).collect();
+        assert_eq!(primes, [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    }
+
+    #[test]
+    fn test_signed_weekday_distance() {
+        // Test case 1: Equal weekdays
+        assert_eq!(signed_weekday_distance(Weekday::Wed, Weekday::Wed), 0);
+
+        // Test case 2: Adjacent weekdays, including across the end of the week
+        assert_eq!(signed_weekday_distance(Weekday::Wed, Weekday::Thu), 1);
+        assert_eq!(signed_weekday_distance(Weekday::Wed, Weekday::Tue), -1);
+        assert_eq!(signed_weekday_distance(Weekday::Sun, Weekday::Mon), 1);
+        assert_eq!(signed_weekday_distance(Weekday::Mon, Weekday::Sun), -1);
+
+        // Test case 3: Three forward is preferred over four backward, and vice versa
+        assert_eq!(signed_weekday_distance(Weekday::Fri, Weekday::Mon), 3);
+        assert_eq!(signed_weekday_distance(Weekday::Mon, Weekday::Fri), -3);
+
+        // Test case 4: Every pair is within -3..=3, antisymmetric and consistent with the week
+        let weekdays = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+        for from in weekdays {
+            for to in weekdays {
+                let distance = signed_weekday_distance(from, to);
+                assert!((-3..=3).contains(&distance));
+                assert_eq!(signed_weekday_distance(to, from), -distance);
+                assert_eq!(
+                    (i64::from(from.num_days_from_monday()) + i64::from(distance)).rem_euclid(7),
+                    i64::from(to.num_days_from_monday())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_next_day_in_window() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+
+        // Test case 1: Before the window, the result is its first day
+        for day in [3, 9] {
+            let result = find_next_day_in_window(&date(10, day), 10, 20);
+            assert_eq!(result, Some(date(10, 10)));
+        }
+
+        // Test case 2: Within the window, the result is the next day
+        for day in 10..20 {
+            let result = find_next_day_in_window(&date(10, day), 10, 20);
+            assert_eq!(result, Some(date(10, day + 1)));
+        }
+
+        // Test case 3: At the end of or after the window, the result is next month's first day
+        for day in [20, 25, 31] {
+            let result = find_next_day_in_window(&date(10, day), 10, 20);
+            assert_eq!(result, Some(date(11, 10)));
+        }
+        let result = find_next_day_in_window(&date(12, 31), 10, 20);
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 1, 10));
+
+        // Test case 4: Months too short for the window are skipped
+        let result = find_next_day_in_window(&date(1, 31), 30, 31);
+        assert_eq!(result, Some(date(3, 30)));
+        let result = find_next_day_in_window(&date(2, 27), 28, 40);
+        assert_eq!(result, Some(date(2, 28)));
+        let result = find_next_day_in_window(&date(2, 28), 28, 40);
+        assert_eq!(result, Some(date(3, 28)));
+
+        // Test case 5: A single-day window is the day of the month
+        for day in [1, 15, 31] {
+            assert_eq!(
+                find_next_day_in_window(&date(1, 31), day, day),
+                find_next_day_of_month(&date(1, 31), day)
+            );
+        }
+
+        // Test case 6: Invalid windows
+        assert_eq!(find_next_day_in_window(&date(10, 3), 0, 20), None);
+        assert_eq!(find_next_day_in_window(&date(10, 3), 20, 10), None);
+        assert_eq!(find_next_day_in_window(&date(10, 3), 32, 40), None);
+    }
+
+    #[test]
+    fn test_iso_weekday_number() {
+        let date = |d| NaiveDate::from_ymd_opt(2023, 10, d).unwrap();
+
+        // Test case 1: Monday is 1 and Sunday is 7
+        assert_eq!(iso_weekday_number(&date(16)), 1);
+        assert_eq!(iso_weekday_number(&date(22)), 7);
+
+        // Test case 2: The numbers count up through an ISO week
+        for (offset, day) in (16..=22).enumerate() {
+            assert_eq!(iso_weekday_number(&date(day)), offset as u32 + 1);
+            assert!(same_iso_week(&date(16), &date(day)));
+        }
+
+        // Test case 3: The week wraps from Sunday to Monday
+        assert_eq!(iso_weekday_number(&date(15)), 7);
+        assert!(!same_iso_week(&date(15), &date(16)));
+    }
+
+    #[test]
+    fn test_week_start_of_annual() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let current_date = date(2024, 5, 1);
+
+        // Test case 1: July 4th, 2024 is a Thursday, late in a week starting on Monday or Sunday
+        let result = week_start_of_annual(&current_date, 7, 4, Weekday::Mon);
+        assert_eq!(result, Some(date(2024, 7, 1)));
+        let result = week_start_of_annual(&current_date, 7, 4, Weekday::Sun);
+        assert_eq!(result, Some(date(2024, 6, 30)));
+
+        // Test case 2: Early in a week starting on Thursday or Wednesday
+        let result = week_start_of_annual(&current_date, 7, 4, Weekday::Thu);
+        assert_eq!(result, Some(date(2024, 7, 4)));
+        let result = week_start_of_annual(&current_date, 7, 4, Weekday::Wed);
+        assert_eq!(result, Some(date(2024, 7, 3)));
+
+        // Test case 3: The week can start in the previous year
+        let result = week_start_of_annual(&current_date, 1, 1, Weekday::Fri);
+        assert_eq!(result, Some(date(2024, 12, 27)));
+
+        // Test case 4: The week can start before the current date
+        let result = week_start_of_annual(&date(2024, 7, 2), 7, 4, Weekday::Mon);
+        assert_eq!(result, Some(date(2024, 7, 1)));
+
+        // Test case 5: Once the date has passed, the next year's week is used
+        let result = week_start_of_annual(&date(2024, 7, 4), 7, 4, Weekday::Mon);
+        assert_eq!(result, Some(date(2025, 6, 30)));
+
+        // Test case 6: An invalid annual date
+        let result = week_start_of_annual(&current_date, 2, 30, Weekday::Mon);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_next_weekday_epoch_day() {
+        let weekdays = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+
+        // Test case 1: Cross-checked against find_next_weekday, including before the common era
+        let dates = [
+            NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 28).unwrap(),
+            NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(1, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(0, 12, 31).unwrap(),
+            NaiveDate::from_ymd_opt(-44, 3, 15).unwrap(),
+        ];
+        for date in dates {
+            let epoch_day = date_to_epoch_day(&date);
+            for weekday in weekdays {
+                let expected = find_next_weekday(&date, weekday).unwrap();
+                let result = next_weekday_epoch_day(epoch_day, weekday).unwrap();
+                assert_eq!(epoch_day_to_date(result), Some(expected));
+                assert_eq!(result, date_to_epoch_day(&expected));
+            }
+        }
+
+        // Test case 2: The conversions round-trip
+        for date in dates {
+            assert_eq!(epoch_day_to_date(date_to_epoch_day(&date)), Some(date));
+        }
+        assert_eq!(
+            date_to_epoch_day(&NaiveDate::from_ymd_opt(1, 1, 1).unwrap()),
+            1
+        );
+        assert_eq!(epoch_day_to_date(i32::MAX), None);
+
+        // Test case 3: The ends of the i32 range
+        assert_eq!(next_weekday_epoch_day(i32::MAX, Weekday::Mon), None);
+        assert_eq!(
+            next_weekday_epoch_day(i32::MIN, Weekday::Mon),
+            Some(i32::MIN + 3)
+        ); // i32::MIN is a Friday
+        assert_eq!(
+            next_weekday_epoch_day(i32::MIN, Weekday::Fri),
+            Some(i32::MIN + 7)
+        );
+        let last_sunday = i32::MAX - (i32::MAX - 1).rem_euclid(7) - 1;
+        assert_eq!(
+            next_weekday_epoch_day(last_sunday - 7, Weekday::Sun),
+            Some(last_sunday)
+        );
     }
 }
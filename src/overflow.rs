@@ -0,0 +1,98 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::days_in_month;
+
+/// What to do when a day of the month does not exist in the target month, such as the 31st in
+/// February.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayOverflow {
+    /// Use the last day of the target month instead.
+    Clamp,
+    /// Give up, so the result is `None`.
+    Skip,
+}
+
+/// Moves a date to the same day of the month in another month.
+///
+/// Unlike adding [`chrono::Months`], which always clamps silently, the caller chooses with
+/// `overflow` what happens when the day does not exist in the target month.
+///
+/// # Arguments
+///
+/// * `date` - The date whose day of the month is kept.
+/// * `year` - The target year.
+/// * `month` - The target month (1-12).
+/// * `overflow` - How to handle a day that the target month does not have.
+///
+/// # Returns
+///
+/// An `Option<NaiveDate>` containing the date in the target month. Returns `None` if the target
+/// month is invalid or out of range, or if the day does not exist and `overflow` is
+/// [`DayOverflow::Skip`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::{same_day_in_month, DayOverflow};
+///
+/// let date = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+/// assert_eq!(
+///     same_day_in_month(&date, 2023, 2, DayOverflow::Clamp),
+///     NaiveDate::from_ymd_opt(2023, 2, 28)
+/// );
+/// assert_eq!(same_day_in_month(&date, 2023, 2, DayOverflow::Skip), None);
+/// ```
+pub fn same_day_in_month(
+    date: &NaiveDate,
+    year: i32,
+    month: u32,
+    overflow: DayOverflow,
+) -> Option<NaiveDate> {
+    let last = days_in_month(year, month)?;
+    let day = match overflow {
+        DayOverflow::Clamp => date.day().min(last),
+        DayOverflow::Skip => date.day(),
+    };
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_day_in_month() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let jan_31 = date(2023, 1, 31);
+
+        // Test case 1: January 31st targeting February under both modes
+        assert_eq!(
+            same_day_in_month(&jan_31, 2023, 2, DayOverflow::Clamp),
+            Some(date(2023, 2, 28))
+        );
+        assert_eq!(same_day_in_month(&jan_31, 2023, 2, DayOverflow::Skip), None);
+        assert_eq!(
+            same_day_in_month(&jan_31, 2024, 2, DayOverflow::Clamp),
+            Some(date(2024, 2, 29))
+        );
+
+        // Test case 2: The day exists, so both modes agree
+        for overflow in [DayOverflow::Clamp, DayOverflow::Skip] {
+            assert_eq!(
+                same_day_in_month(&jan_31, 2023, 3, overflow),
+                Some(date(2023, 3, 31))
+            );
+            assert_eq!(
+                same_day_in_month(&date(2023, 1, 15), 2023, 4, overflow),
+                Some(date(2023, 4, 15))
+            );
+        }
+
+        // Test case 3: An invalid target month
+        assert_eq!(
+            same_day_in_month(&jan_31, 2023, 13, DayOverflow::Clamp),
+            None
+        );
+    }
+}
@@ -0,0 +1,2625 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Datelike, Days, Months, NaiveDate, TimeDelta, Weekday};
+
+use crate::{
+    find_next_annual_date, find_next_day_of_month, find_next_weekday, find_nth_weekday_of_month,
+    find_previous_annual_date, find_previous_day_of_month, find_previous_weekday, validate_ymd,
+    NextDayError,
+};
+
+/// A rule describing a date that repeats on a regular schedule.
+///
+/// A `Recurrence` can be parsed from a compact string form, which makes it easy to store
+/// in configuration files or environment variables:
+///
+/// * `weekly:MON` - every week on the given weekday.
+/// * `monthly:15` - every month on the given day of the month.
+/// * `annual:02-29` - every year on the given month and day.
+/// * `nth:1:MON` - every month on the given occurrence (1-5) of the given weekday.
+/// * `weeks:2:MON:2024-01-01` - every 2 weeks on the given weekday, in phase with the given
+///   anchor date.
+/// * `weekly:MON except monthly:1` - the occurrences of the first recurrence that are not
+///   occurrences of the second. Exclusions chain on their base, and parentheses group an
+///   excluded recurrence that is itself an exclusion: `weekly:MON except (monthly:1 except
+///   weekly:MON)`.
+///
+/// `Recurrence` is `Clone` but, since version 0.2.0, no longer `Copy`, because the recurrences
+/// boxed in [`Recurrence::Except`] cannot be copied. Code that uses a recurrence after moving it
+/// needs to clone it or borrow it instead.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::Recurrence;
+///
+/// let recurrence: Recurrence = "weekly:MON".parse().unwrap();
+/// assert_eq!(recurrence, Recurrence::Weekly(Weekday::Mon));
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // A Sunday
+/// let next = recurrence.next(&current_date).unwrap();
+/// assert_eq!(next, NaiveDate::from_ymd_opt(2023, 10, 16).unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Recurrence {
+    /// Every week on the given weekday.
+    Weekly(Weekday),
+    /// Every month on the given day of the month (1-31).
+    DayOfMonth(u32),
+    /// Every year on the given month (1-12) and day (1-31).
+    AnnualDate { month: u32, day: u32 },
+    /// Every month on the `n`th (1-5) occurrence of the given weekday, such as the first Monday
+    /// of the month. Months with only four occurrences of the weekday are skipped when `n = 5`.
+    NthWeekdayOfMonth { weekday: Weekday, n: u32 },
+    /// Every `n` weeks on the given weekday.
+    ///
+    /// The `anchor` only fixes the phase: the occurrences are the dates falling on `weekday`
+    /// in the week of `anchor` and every `n`th week before and after it. With `n = 1` this is
+    /// the same as [`Recurrence::Weekly`], and with `n = 2` it is a biweekly schedule.
+    EveryNWeeks {
+        weekday: Weekday,
+        n: u32,
+        anchor: NaiveDate,
+    },
+    /// The occurrences of `base` on which `exclude` does not occur, such as every Monday
+    /// except the first Monday of the month.
+    ///
+    /// Finding an occurrence examines at most 1000 consecutive base occurrences, so a
+    /// recurrence that excludes all of its base occurrences never occurs instead of searching
+    /// forever.
+    Except {
+        base: Box<Recurrence>,
+        exclude: Box<Recurrence>,
+    },
+}
+
+/// The number of consecutive base occurrences a [`Recurrence::Except`] examines before giving
+/// up on finding one that is not excluded.
+const EXCEPT_SEARCH_LIMIT: usize = 1000;
+
+impl Recurrence {
+    /// Finds the next occurrence strictly after `current_date`.
+    ///
+    /// This dispatches to [`find_next_weekday`], [`find_next_day_of_month`],
+    /// [`find_next_annual_date`] or [`find_nth_weekday_of_month`] depending on the variant. A
+    /// [`Recurrence::EveryNWeeks`] with
+    /// `n = 0` never occurs, and a [`Recurrence::Except`] steps through its base occurrences
+    /// until one is not excluded.
+    ///
+    /// Feeding the result back in always advances: for any `date`, `next(next(date))` is
+    /// strictly after `next(date)`, so a `date = next(date)` loop never stalls.
+    pub fn next(&self, current_date: &NaiveDate) -> Option<NaiveDate> {
+        let next = match *self {
+            Recurrence::Weekly(weekday) => find_next_weekday(current_date, weekday),
+            Recurrence::DayOfMonth(day) => find_next_day_of_month(current_date, day),
+            Recurrence::AnnualDate { month, day } => {
+                find_next_annual_date(current_date, month, day)
+            }
+            Recurrence::NthWeekdayOfMonth { weekday, n } => {
+                nearest_nth_weekday_of_month(current_date, weekday, n, true)
+            }
+            Recurrence::EveryNWeeks { weekday, n, anchor } => {
+                let (first, period) = every_n_weeks_grid(weekday, n, &anchor)?;
+                let days = (*current_date - first).num_days();
+                first.checked_add_signed(TimeDelta::try_days(
+                    (days.div_euclid(period) + 1) * period,
+                )?)
+            }
+            Recurrence::Except {
+                ref base,
+                ref exclude,
+            } => std::iter::successors(base.next(current_date), |date| base.next(date))
+                .take(EXCEPT_SEARCH_LIMIT)
+                .find(|date| !exclude.matches(date)),
+        };
+        debug_assert!(next.is_none_or(|date| date > *current_date));
+        next
+    }
+
+    /// Finds the next occurrence strictly after `from`, together with the weekday it falls on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use next_matching_day::Recurrence;
+    ///
+    /// let from = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
+    /// let result = Recurrence::DayOfMonth(1).next_with_weekday(&from);
+    /// assert_eq!(
+    ///     result,
+    ///     Some((NaiveDate::from_ymd_opt(2023, 11, 1).unwrap(), Weekday::Wed))
+    /// );
+    /// ```
+    pub fn next_with_weekday(&self, from: &NaiveDate) -> Option<(NaiveDate, Weekday)> {
+        self.next(from).map(|date| (date, date.weekday()))
+    }
+
+    /// Checks that the recurrence can occur at all.
+    ///
+    /// A recurrence that fails validation never produces an occurrence, so checking it when it
+    /// is created catches bad configuration early instead of at the first `next()` returning
+    /// `None`.
+    ///
+    /// # Errors
+    ///
+    /// * [`NextDayError::InvalidDay`] if a day-of-month recurrence is not between 1 and 31, or
+    ///   an annual date's day never occurs in its month (February 29 is allowed).
+    /// * [`NextDayError::InvalidMonth`] if an annual date's month is not between 1 and 12.
+    /// * [`NextDayError::InvalidOccurrence`] if an nth-weekday-of-month recurrence has an `n`
+    ///   that is not between 1 and 5.
+    /// * [`NextDayError::InvalidInterval`] if an every-n-weeks recurrence has `n = 0`.
+    ///
+    /// An exclusion is checked by validating both its base and its excluded recurrence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use next_matching_day::{NextDayError, Recurrence};
+    ///
+    /// assert_eq!(Recurrence::AnnualDate { month: 2, day: 29 }.validate(), Ok(()));
+    /// assert_eq!(
+    ///     Recurrence::AnnualDate { month: 2, day: 30 }.validate(),
+    ///     Err(NextDayError::InvalidDay(30))
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), NextDayError> {
+        match *self {
+            Recurrence::Weekly(_) => Ok(()),
+            Recurrence::DayOfMonth(day) => {
+                if (1..=31).contains(&day) {
+                    Ok(())
+                } else {
+                    Err(NextDayError::InvalidDay(day))
+                }
+            }
+            Recurrence::AnnualDate { month, day } => {
+                // Validate against a leap year so that February 29 is accepted.
+                validate_ymd(2000, month, day).map(|_| ())
+            }
+            Recurrence::NthWeekdayOfMonth { n, .. } => {
+                if (1..=5).contains(&n) {
+                    Ok(())
+                } else {
+                    Err(NextDayError::InvalidOccurrence(n))
+                }
+            }
+            Recurrence::EveryNWeeks { n, .. } => {
+                if n >= 1 {
+                    Ok(())
+                } else {
+                    Err(NextDayError::InvalidInterval(n))
+                }
+            }
+            Recurrence::Except {
+                ref base,
+                ref exclude,
+            } => {
+                base.validate()?;
+                exclude.validate()
+            }
+        }
+    }
+
+    /// Returns an iterator over the occurrences strictly after `from`, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use next_matching_day::Recurrence;
+    ///
+    /// let from = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+    /// let dates: Vec<_> = Recurrence::DayOfMonth(30).occurrences(&from).take(2).collect();
+    /// assert_eq!(
+    ///     dates,
+    ///     [
+    ///         NaiveDate::from_ymd_opt(2023, 1, 30).unwrap(),
+    ///         NaiveDate::from_ymd_opt(2023, 3, 30).unwrap(),
+    ///     ]
+    /// );
+    /// ```
+    pub fn occurrences(&self, from: &NaiveDate) -> Occurrences {
+        Occurrences {
+            recurrence: self.clone(),
+            next: self.next(from),
+        }
+    }
+
+    /// Finds the last occurrence strictly before `current_date`.
+    ///
+    /// This is the inverse of [`Recurrence::next`]: for any occurrence `date`,
+    /// `next(prev_occurrence(date))` is `date` again, which lets a scheduler step back through
+    /// the dates it advanced over. It dispatches to [`find_previous_weekday`],
+    /// [`find_previous_day_of_month`] or [`find_previous_annual_date`] depending on the variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use next_matching_day::Recurrence;
+    ///
+    /// let recurrence = Recurrence::DayOfMonth(31);
+    /// let date = NaiveDate::from_ymd_opt(2023, 3, 31).unwrap();
+    /// let previous = recurrence.prev_occurrence(&date).unwrap();
+    /// assert_eq!(previous, NaiveDate::from_ymd_opt(2023, 1, 31).unwrap());
+    /// assert_eq!(recurrence.next(&previous), Some(date));
+    /// ```
+    pub fn prev_occurrence(&self, current_date: &NaiveDate) -> Option<NaiveDate> {
+        match *self {
+            Recurrence::Weekly(weekday) => find_previous_weekday(current_date, weekday),
+            Recurrence::DayOfMonth(day) => find_previous_day_of_month(current_date, day),
+            Recurrence::AnnualDate { month, day } => {
+                find_previous_annual_date(current_date, month, day)
+            }
+            Recurrence::NthWeekdayOfMonth { weekday, n } => {
+                nearest_nth_weekday_of_month(current_date, weekday, n, false)
+            }
+            Recurrence::EveryNWeeks { weekday, n, anchor } => {
+                let (first, period) = every_n_weeks_grid(weekday, n, &anchor)?;
+                let days = (*current_date - first).num_days();
+                first.checked_add_signed(TimeDelta::try_days(
+                    (days - 1).div_euclid(period) * period,
+                )?)
+            }
+            Recurrence::Except {
+                ref base,
+                ref exclude,
+            } => std::iter::successors(base.prev_occurrence(current_date), |date| {
+                base.prev_occurrence(date)
+            })
+            .take(EXCEPT_SEARCH_LIMIT)
+            .find(|date| !exclude.matches(date)),
+        }
+    }
+
+    /// Returns up to `n` occurrences strictly before `before`, most recent first.
+    ///
+    /// This repeatedly applies [`Recurrence::prev_occurrence`], so it pages backward through
+    /// history the same way [`Recurrence::occurrences`] pages forward. Fewer than `n` dates are
+    /// returned if the search runs past [`NaiveDate::MIN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use next_matching_day::Recurrence;
+    ///
+    /// let before = NaiveDate::from_ymd_opt(2023, 4, 1).unwrap();
+    /// assert_eq!(
+    ///     Recurrence::DayOfMonth(31).prev_n(&before, 2),
+    ///     [
+    ///         NaiveDate::from_ymd_opt(2023, 3, 31).unwrap(),
+    ///         NaiveDate::from_ymd_opt(2023, 1, 31).unwrap(),
+    ///     ]
+    /// );
+    /// ```
+    pub fn prev_n(&self, before: &NaiveDate, n: usize) -> Vec<NaiveDate> {
+        let mut dates = Vec::with_capacity(n);
+        let mut current = *before;
+        while dates.len() < n {
+            match self.prev_occurrence(&current) {
+                Some(date) => {
+                    dates.push(date);
+                    current = date;
+                }
+                None => break,
+            }
+        }
+        dates
+    }
+
+    /// Returns `true` if `date` is an occurrence of the recurrence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use next_matching_day::Recurrence;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2023, 12, 25).unwrap();
+    /// assert!(Recurrence::DayOfMonth(25).matches(&date));
+    /// assert!(!Recurrence::DayOfMonth(24).matches(&date));
+    /// ```
+    pub fn matches(&self, date: &NaiveDate) -> bool {
+        match *self {
+            Recurrence::Weekly(weekday) => date.weekday() == weekday,
+            Recurrence::DayOfMonth(day) => date.day() == day,
+            Recurrence::AnnualDate { month, day } => date.month() == month && date.day() == day,
+            Recurrence::NthWeekdayOfMonth { weekday, n } => {
+                date.weekday() == weekday && (date.day() - 1) / 7 + 1 == n
+            }
+            Recurrence::EveryNWeeks { weekday, n, anchor } => {
+                every_n_weeks_grid(weekday, n, &anchor)
+                    .is_some_and(|(first, period)| (*date - first).num_days() % period == 0)
+            }
+            Recurrence::Except {
+                ref base,
+                ref exclude,
+            } => base.matches(date) && !exclude.matches(date),
+        }
+    }
+
+    /// Aligns `date` forward onto the recurrence.
+    ///
+    /// This is the inclusive counterpart of [`next`](Recurrence::next): it returns `date` itself
+    /// if it is an occurrence, and the next occurrence otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use next_matching_day::Recurrence;
+    ///
+    /// let recurrence = Recurrence::Weekly(Weekday::Mon);
+    /// let monday = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap();
+    /// assert_eq!(recurrence.align_forward(&monday), Some(monday));
+    ///
+    /// let tuesday = NaiveDate::from_ymd_opt(2023, 10, 17).unwrap();
+    /// assert_eq!(
+    ///     recurrence.align_forward(&tuesday),
+    ///     NaiveDate::from_ymd_opt(2023, 10, 23)
+    /// );
+    /// ```
+    pub fn align_forward(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        if self.matches(date) {
+            Some(*date)
+        } else {
+            self.next(date)
+        }
+    }
+
+    /// Describes the step from occurrence `a` to the consecutive occurrence `b`.
+    ///
+    /// Weekly recurrences always step by 7 days (and every-n-weeks ones by `7 * n` days),
+    /// day-of-month and nth-weekday-of-month recurrences by a number of months
+    /// (more than one when months without the day are skipped), and annual recurrences by a
+    /// number of years (4 or 8 for February 29). Exclusions step in the same unit as their base
+    /// recurrence, by a multiple of the base step when excluded occurrences are skipped.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<RecurrenceStep>` describing the interval. Returns `None` if `a` is not an
+    /// occurrence or `b` is not the occurrence immediately following it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use next_matching_day::{Recurrence, RecurrenceStep};
+    ///
+    /// let a = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+    /// let b = NaiveDate::from_ymd_opt(2023, 3, 31).unwrap();
+    /// let step = Recurrence::DayOfMonth(31).step_between(&a, &b);
+    /// assert_eq!(step, Some(RecurrenceStep::Months(2)));
+    /// ```
+    pub fn step_between(&self, a: &NaiveDate, b: &NaiveDate) -> Option<RecurrenceStep> {
+        if !self.matches(a) || self.next(a) != Some(*b) {
+            return None;
+        }
+
+        Some(self.step_in_units(a, b))
+    }
+
+    /// Measures the interval from `a` to `b` in the units the recurrence steps by, without
+    /// checking that they are consecutive occurrences.
+    fn step_in_units(&self, a: &NaiveDate, b: &NaiveDate) -> RecurrenceStep {
+        match self {
+            Recurrence::Weekly(_) | Recurrence::EveryNWeeks { .. } => {
+                RecurrenceStep::Days((*b - *a).num_days() as u32)
+            }
+            Recurrence::DayOfMonth(_) | Recurrence::NthWeekdayOfMonth { .. } => {
+                let months = (b.year() - a.year()) * 12 + b.month() as i32 - a.month() as i32;
+                RecurrenceStep::Months(months as u32)
+            }
+            Recurrence::AnnualDate { .. } => RecurrenceStep::Years((b.year() - a.year()) as u32),
+            Recurrence::Except { base, .. } => base.step_in_units(a, b),
+        }
+    }
+
+    /// Finds the nearest occurrences on either side of `date`.
+    ///
+    /// The first element is the last occurrence strictly before `date` and the second is the
+    /// first occurrence strictly after it, so `date` itself is never included even if it
+    /// matches the recurrence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use next_matching_day::Recurrence;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2023, 10, 20).unwrap();
+    /// let (previous, next) = Recurrence::DayOfMonth(15).surrounding(&date);
+    /// assert_eq!(previous, NaiveDate::from_ymd_opt(2023, 10, 15));
+    /// assert_eq!(next, NaiveDate::from_ymd_opt(2023, 11, 15));
+    /// ```
+    pub fn surrounding(&self, date: &NaiveDate) -> (Option<NaiveDate>, Option<NaiveDate>) {
+        (self.prev_occurrence(date), self.next(date))
+    }
+
+    /// Counts the occurrences between `start` and `end`, both inclusive.
+    ///
+    /// Weekly, every-n-weeks and annual recurrences are counted arithmetically, while day-of-month
+    /// and nth-weekday-of-month recurrences walk the months in the range so that short months are
+    /// skipped. Exclusions
+    /// walk their occurrences in the range one at a time.
+    ///
+    /// # Returns
+    ///
+    /// The number of occurrences in the range, or 0 if `end` is before `start` or the
+    /// recurrence can never occur.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use next_matching_day::Recurrence;
+    ///
+    /// // The 31st of the month occurs 7 times in a year.
+    /// let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+    /// assert_eq!(Recurrence::DayOfMonth(31).count_occurrences(&start, &end), 7);
+    /// ```
+    pub fn count_occurrences(&self, start: &NaiveDate, end: &NaiveDate) -> u64 {
+        if end < start {
+            return 0;
+        }
+
+        match *self {
+            Recurrence::Weekly(weekday) => {
+                let offset = weekday.days_since(start.weekday()) as i64;
+                let days = (*end - *start).num_days();
+                if offset > days {
+                    0
+                } else {
+                    ((days - offset) / 7 + 1) as u64
+                }
+            }
+            Recurrence::DayOfMonth(day) => {
+                let mut count = 0;
+                let mut month_start = start.with_day(1);
+                while let Some(first) = month_start.filter(|first| first <= end) {
+                    if let Some(date) = first.with_day(day) {
+                        if date >= *start && date <= *end {
+                            count += 1;
+                        }
+                    }
+                    month_start = first.checked_add_months(Months::new(1));
+                }
+                count
+            }
+            Recurrence::NthWeekdayOfMonth { weekday, n } => {
+                let mut count = 0;
+                let mut month_start = start.with_day(1);
+                while let Some(first) = month_start.filter(|first| first <= end) {
+                    if let Some(date) =
+                        find_nth_weekday_of_month(first.year(), first.month(), weekday, n)
+                    {
+                        if date >= *start && date <= *end {
+                            count += 1;
+                        }
+                    }
+                    month_start = first.checked_add_months(Months::new(1));
+                }
+                count
+            }
+            Recurrence::AnnualDate { month, day } => {
+                // Validate against a leap year so that February 29 is accepted.
+                if NaiveDate::from_ymd_opt(2000, month, day).is_none() {
+                    return 0;
+                }
+
+                let (first_year, last_year) = (start.year(), end.year());
+                let mut count = if month == 2 && day == 29 {
+                    leap_years_through(last_year) - leap_years_through(first_year - 1)
+                } else {
+                    (last_year - first_year + 1) as i64
+                };
+                if NaiveDate::from_ymd_opt(first_year, month, day).is_some_and(|d| d < *start) {
+                    count -= 1;
+                }
+                if NaiveDate::from_ymd_opt(last_year, month, day).is_some_and(|d| d > *end) {
+                    count -= 1;
+                }
+                count.max(0) as u64
+            }
+            Recurrence::EveryNWeeks { n, .. } => match self.align_forward(start) {
+                Some(first) if first <= *end => {
+                    ((*end - first).num_days() as u64) / (7 * n as u64) + 1
+                }
+                _ => 0,
+            },
+            Recurrence::Except { .. } => {
+                std::iter::successors(self.align_forward(start), |date| self.next(date))
+                    .take_while(|date| date <= end)
+                    .count() as u64
+            }
+        }
+    }
+
+    /// Returns the position of `date` in the sequence of occurrences starting at `anchor`.
+    ///
+    /// This is the number of occurrences from `anchor` to `date`, both inclusive, as counted by
+    /// [`Recurrence::count_occurrences`]. If both dates are occurrences, the anchor is number 1
+    /// and `date` is the returned number, such as "the 7th Monday since the anchor".
+    ///
+    /// # Returns
+    ///
+    /// An `Option<u64>` containing the number of occurrences. Returns `None` if `date` is
+    /// before `anchor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use next_matching_day::Recurrence;
+    ///
+    /// let anchor = NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(); // A Monday
+    /// let date = NaiveDate::from_ymd_opt(2023, 11, 13).unwrap(); // Six weeks later
+    /// let index = Recurrence::Weekly(Weekday::Mon).occurrence_index(&anchor, &date);
+    /// assert_eq!(index, Some(7));
+    /// ```
+    pub fn occurrence_index(&self, anchor: &NaiveDate, date: &NaiveDate) -> Option<u64> {
+        if date < anchor {
+            return None;
+        }
+        Some(self.count_occurrences(anchor, date))
+    }
+
+    /// Counts the occurrences strictly after `from` and up to and including `deadline`.
+    ///
+    /// This is [`Recurrence::count_occurrences`] with an exclusive start, matching how every
+    /// finder treats its start date. For example, the number of paydays left before a lease
+    /// ends, not counting today's.
+    ///
+    /// # Returns
+    ///
+    /// The number of remaining occurrences, or 0 if `deadline` is on or before `from`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use next_matching_day::Recurrence;
+    ///
+    /// let from = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
+    /// let deadline = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+    /// assert_eq!(Recurrence::DayOfMonth(15).count_occurrences_until(&from, &deadline), 3);
+    /// ```
+    pub fn count_occurrences_until(&self, from: &NaiveDate, deadline: &NaiveDate) -> u64 {
+        match from.succ_opt() {
+            Some(start) => self.count_occurrences(&start, deadline),
+            None => 0,
+        }
+    }
+
+    /// Lists the dates the recurrence hits within a calendar month, in ascending order.
+    ///
+    /// A weekly recurrence hits 4 or 5 dates and an every-n-weeks recurrence at most that many,
+    /// while day-of-month, nth-weekday-of-month and annual recurrences hit at most one. The result is empty if the
+    /// month is invalid or the recurrence does not occur in it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use next_matching_day::Recurrence;
+    ///
+    /// let dates = Recurrence::DayOfMonth(31).occurrences_in_month(2023, 2);
+    /// assert!(dates.is_empty());
+    ///
+    /// let dates = Recurrence::Weekly(Weekday::Tue).occurrences_in_month(2023, 10);
+    /// assert_eq!(dates.len(), 5);
+    /// ```
+    pub fn occurrences_in_month(&self, year: i32, month: u32) -> Vec<NaiveDate> {
+        let Some(month_start) = NaiveDate::from_ymd_opt(year, month, 1) else {
+            return Vec::new();
+        };
+
+        match *self {
+            Recurrence::Weekly(weekday) => {
+                let offset = weekday.days_since(month_start.weekday()) as u64;
+                std::iter::successors(month_start.checked_add_days(Days::new(offset)), |date| {
+                    date.checked_add_days(Days::new(7))
+                })
+                .take_while(|date| date.month() == month)
+                .collect()
+            }
+            Recurrence::DayOfMonth(day) => month_start.with_day(day).into_iter().collect(),
+            Recurrence::NthWeekdayOfMonth { weekday, n } => {
+                find_nth_weekday_of_month(year, month, weekday, n)
+                    .into_iter()
+                    .collect()
+            }
+            Recurrence::AnnualDate {
+                month: annual_month,
+                day,
+            } => {
+                if annual_month == month {
+                    month_start.with_day(day).into_iter().collect()
+                } else {
+                    Vec::new()
+                }
+            }
+            Recurrence::EveryNWeeks { .. } | Recurrence::Except { .. } => {
+                std::iter::successors(self.align_forward(&month_start), |date| self.next(date))
+                    .take_while(|date| date.month() == month)
+                    .collect()
+            }
+        }
+    }
+
+    /// Lists the upcoming occurrences grouped by calendar month, for agenda views.
+    ///
+    /// The result has one entry for each of `months` consecutive calendar months, starting
+    /// with the month containing `from`. Each entry is the year, the month and the occurrences
+    /// in that month strictly after `from`, as listed by [`Recurrence::occurrences_in_month`].
+    /// Months without any occurrence are still included, with an empty list, so the entries
+    /// always line up with the calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use next_matching_day::Recurrence;
+    ///
+    /// let from = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+    /// let grouped = Recurrence::DayOfMonth(31).occurrences_by_month(&from, 3);
+    /// assert_eq!(
+    ///     grouped,
+    ///     [
+    ///         (2023, 1, vec![]),
+    ///         (2023, 2, vec![]),
+    ///         (2023, 3, vec![NaiveDate::from_ymd_opt(2023, 3, 31).unwrap()]),
+    ///     ]
+    /// );
+    /// ```
+    pub fn occurrences_by_month(
+        &self,
+        from: &NaiveDate,
+        months: u32,
+    ) -> Vec<(i32, u32, Vec<NaiveDate>)> {
+        let Some(month_start) = from.with_day(1) else {
+            return Vec::new();
+        };
+        (0..months)
+            .map_while(|i| month_start.checked_add_months(Months::new(i)))
+            .map(|month| {
+                let mut dates = self.occurrences_in_month(month.year(), month.month());
+                dates.retain(|date| date > from);
+                (month.year(), month.month(), dates)
+            })
+            .collect()
+    }
+}
+
+/// Filters a stream of dates down to the occurrences of a recurrence.
+///
+/// Only the dates for which [`Recurrence::matches`] returns `true` are yielded, in the order of
+/// the input, so any date source such as [`dates_in_range`](crate::dates_in_range) can be piped
+/// through a recurrence.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::{dates_in_range, filter_recurrence, Recurrence};
+///
+/// let start = NaiveDate::from_ymd_opt(2023, 10, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2023, 10, 31).unwrap();
+/// let mondays = filter_recurrence(dates_in_range(&start, &end), Recurrence::Weekly(Weekday::Mon));
+/// assert_eq!(mondays.count(), 5);
+/// ```
+pub fn filter_recurrence(
+    iter: impl Iterator<Item = NaiveDate>,
+    rec: Recurrence,
+) -> impl Iterator<Item = NaiveDate> {
+    iter.filter(move |date| rec.matches(date))
+}
+
+/// Guesses the most specific recurrence that has both dates as occurrences.
+///
+/// Dates on the same month and day give a [`Recurrence::AnnualDate`], dates on the same day of
+/// the month give a [`Recurrence::DayOfMonth`], and dates on the same weekday give a
+/// [`Recurrence::Weekly`]. When several fit, the first in that order wins, since it occurs least
+/// often: January 1st, 2024 and April 1st, 2024 are both Mondays, but the inferred recurrence is
+/// the 1st of every month. The dates do not need to be consecutive occurrences.
+///
+/// # Returns
+///
+/// An `Option<Recurrence>` containing the inferred recurrence. Returns `None` if the dates are
+/// equal, since a single date fits every recurrence through it, or if no recurrence fits.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::{infer_recurrence, Recurrence};
+///
+/// let a = NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(); // A Monday
+/// let b = NaiveDate::from_ymd_opt(2023, 10, 30).unwrap(); // A Monday
+/// assert_eq!(infer_recurrence(&a, &b), Some(Recurrence::Weekly(Weekday::Mon)));
+/// ```
+pub fn infer_recurrence(a: &NaiveDate, b: &NaiveDate) -> Option<Recurrence> {
+    if a == b {
+        None
+    } else if a.month() == b.month() && a.day() == b.day() {
+        Some(Recurrence::AnnualDate {
+            month: a.month(),
+            day: a.day(),
+        })
+    } else if a.day() == b.day() {
+        Some(Recurrence::DayOfMonth(a.day()))
+    } else if a.weekday() == b.weekday() {
+        Some(Recurrence::Weekly(a.weekday()))
+    } else {
+        None
+    }
+}
+
+/// The interval between two consecutive occurrences of a [`Recurrence`], as returned by
+/// [`Recurrence::step_between`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RecurrenceStep {
+    /// A fixed number of days.
+    Days(u32),
+    /// A number of calendar months.
+    Months(u32),
+    /// A number of calendar years.
+    Years(u32),
+}
+
+/// An iterator over the occurrences of a [`Recurrence`], created by [`Recurrence::occurrences`].
+#[derive(Debug, Clone)]
+pub struct Occurrences {
+    recurrence: Recurrence,
+    next: Option<NaiveDate>,
+}
+
+impl Iterator for Occurrences {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let date = self.next?;
+        self.next = self.recurrence.next(&date);
+        Some(date)
+    }
+}
+
+/// A cursor stepping through the occurrences of a [`Recurrence`] one at a time.
+///
+/// The cursor starts just after the `from` date given to [`RecurrenceCursor::new`] and computes
+/// each occurrence only when it is requested, so callers decide how many occurrences are
+/// materialized and can stop on external conditions. [`peek`](RecurrenceCursor::peek) looks at
+/// the upcoming occurrence without moving past it, and [`advance`](RecurrenceCursor::advance)
+/// returns it and moves on.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::{Recurrence, RecurrenceCursor};
+///
+/// let from = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+/// let mut cursor = RecurrenceCursor::new(Recurrence::DayOfMonth(31), from);
+/// assert_eq!(cursor.peek(), NaiveDate::from_ymd_opt(2023, 3, 31));
+/// assert_eq!(cursor.advance(), NaiveDate::from_ymd_opt(2023, 3, 31));
+/// assert_eq!(cursor.advance(), NaiveDate::from_ymd_opt(2023, 5, 31));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RecurrenceCursor {
+    recurrence: Recurrence,
+    position: NaiveDate,
+    peeked: Option<Option<NaiveDate>>,
+}
+
+impl RecurrenceCursor {
+    /// Creates a cursor over the occurrences of `recurrence` strictly after `from`.
+    pub fn new(recurrence: Recurrence, from: NaiveDate) -> Self {
+        RecurrenceCursor {
+            recurrence,
+            position: from,
+            peeked: None,
+        }
+    }
+
+    /// Returns the upcoming occurrence without moving past it.
+    ///
+    /// Returns `None` once the occurrences are exhausted because the calculation overflows.
+    pub fn peek(&mut self) -> Option<NaiveDate> {
+        let (recurrence, position) = (&self.recurrence, &self.position);
+        *self.peeked.get_or_insert_with(|| recurrence.next(position))
+    }
+
+    /// Returns the upcoming occurrence and moves the cursor past it.
+    ///
+    /// Returns `None` once the occurrences are exhausted because the calculation overflows.
+    pub fn advance(&mut self) -> Option<NaiveDate> {
+        let date = self.peek()?;
+        self.position = date;
+        self.peeked = None;
+        Some(date)
+    }
+}
+
+impl Iterator for RecurrenceCursor {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.advance()
+    }
+}
+
+/// Merges the occurrences of several recurrences into one chronologically sorted stream.
+///
+/// Each item is the index of the recurrence in `recurrences` and the date of one of its
+/// occurrences strictly after `from`. Every recurrence advances independently, so this is a
+/// k-way merge of their [`Recurrence::occurrences`] iterators. When two recurrences fall on the
+/// same date, the one with the lower index is yielded first.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::{merge_next, Recurrence};
+///
+/// let recurrences = vec![Recurrence::DayOfMonth(20), Recurrence::Weekly(Weekday::Mon)];
+/// let from = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // A Sunday
+/// let merged: Vec<_> = merge_next(recurrences, from).take(3).collect();
+/// assert_eq!(
+///     merged,
+///     [
+///         (1, NaiveDate::from_ymd_opt(2023, 10, 16).unwrap()),
+///         (0, NaiveDate::from_ymd_opt(2023, 10, 20).unwrap()),
+///         (1, NaiveDate::from_ymd_opt(2023, 10, 23).unwrap()),
+///     ]
+/// );
+/// ```
+pub fn merge_next(
+    recurrences: Vec<Recurrence>,
+    from: NaiveDate,
+) -> impl Iterator<Item = (usize, NaiveDate)> {
+    let mut iters: Vec<Occurrences> = recurrences.iter().map(|r| r.occurrences(&from)).collect();
+    let mut heap = BinaryHeap::new();
+    for (index, iter) in iters.iter_mut().enumerate() {
+        if let Some(date) = iter.next() {
+            heap.push(Reverse((date, index)));
+        }
+    }
+
+    std::iter::from_fn(move || {
+        let Reverse((date, index)) = heap.pop()?;
+        if let Some(next) = iters[index].next() {
+            heap.push(Reverse((next, index)));
+        }
+        Some((index, date))
+    })
+}
+
+/// Finds the soonest upcoming occurrence among several recurrences.
+///
+/// Each recurrence's next occurrence strictly after `from` is computed, and the earliest one is
+/// returned together with the index of its recurrence in `candidates`. When several
+/// recurrences share the earliest date, the lowest index wins.
+///
+/// # Returns
+///
+/// An `Option<(usize, NaiveDate)>` containing the index and the date. Returns `None` if
+/// `candidates` is empty or none of them has a next occurrence.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::{next_across, Recurrence};
+///
+/// let candidates = [Recurrence::DayOfMonth(1), Recurrence::Weekly(Weekday::Mon)];
+/// let from = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // A Sunday
+/// assert_eq!(
+///     next_across(&candidates, &from),
+///     Some((1, NaiveDate::from_ymd_opt(2023, 10, 16).unwrap()))
+/// );
+/// ```
+pub fn next_across(candidates: &[Recurrence], from: &NaiveDate) -> Option<(usize, NaiveDate)> {
+    candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(index, recurrence)| Some((index, recurrence.next(from)?)))
+        .min_by_key(|&(index, date)| (date, index))
+}
+
+/// Finds the next occurrence of each of several recurrences.
+///
+/// The result has one entry per recurrence, in the same order as `recurrences`, holding its
+/// next occurrence strictly after `from`, or `None` if it has none. This refreshes a whole set
+/// of stored rules at once, whereas [`next_across`] only keeps the soonest of them.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::{next_for_each, Recurrence};
+///
+/// let recurrences = [Recurrence::Weekly(Weekday::Mon), Recurrence::DayOfMonth(1)];
+/// let from = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
+/// assert_eq!(
+///     next_for_each(&recurrences, &from),
+///     [
+///         NaiveDate::from_ymd_opt(2023, 10, 16),
+///         NaiveDate::from_ymd_opt(2023, 11, 1),
+///     ]
+/// );
+/// ```
+pub fn next_for_each(recurrences: &[Recurrence], from: &NaiveDate) -> Vec<Option<NaiveDate>> {
+    recurrences
+        .iter()
+        .map(|recurrence| recurrence.next(from))
+        .collect()
+}
+
+/// Finds the soonest upcoming date among several recurrences.
+///
+/// This is [`next_across`] without the index of the recurrence, for fallback chains such as
+/// "on the 1st, or the next Monday if that comes sooner".
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::{find_next_first_of, Recurrence};
+///
+/// let candidates = [Recurrence::DayOfMonth(1), Recurrence::Weekly(Weekday::Mon)];
+/// let from = NaiveDate::from_ymd_opt(2023, 10, 31).unwrap(); // A Tuesday
+/// assert_eq!(
+///     find_next_first_of(&candidates, &from),
+///     NaiveDate::from_ymd_opt(2023, 11, 1)
+/// );
+/// ```
+pub fn find_next_first_of(candidates: &[Recurrence], from: &NaiveDate) -> Option<NaiveDate> {
+    next_across(candidates, from).map(|(_, date)| date)
+}
+
+/// Finds the occurrence nearest to `date` among several recurrences, in either direction.
+///
+/// For each recurrence, the candidates are `date` itself if it is an occurrence, the last
+/// occurrence before it and the first occurrence after it. The candidate the fewest days away
+/// from `date` is returned together with the index of its recurrence in `recurrences`. When an
+/// earlier and a later candidate are equally far away, the later one wins, and among candidates
+/// on the same date the lowest index wins.
+///
+/// # Returns
+///
+/// An `Option<(usize, NaiveDate)>` containing the index and the date. Returns `None` if
+/// `recurrences` is empty or none of them occurs.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::{nearest_across, Recurrence};
+///
+/// let recurrences = [Recurrence::Weekly(Weekday::Mon), Recurrence::DayOfMonth(15)];
+/// let date = NaiveDate::from_ymd_opt(2023, 8, 16).unwrap(); // A Wednesday
+/// assert_eq!(
+///     nearest_across(&recurrences, &date),
+///     Some((1, NaiveDate::from_ymd_opt(2023, 8, 15).unwrap()))
+/// );
+/// ```
+pub fn nearest_across(recurrences: &[Recurrence], date: &NaiveDate) -> Option<(usize, NaiveDate)> {
+    recurrences
+        .iter()
+        .enumerate()
+        .flat_map(|(index, recurrence)| {
+            let current = Some(*date).filter(|date| recurrence.matches(date));
+            let (previous, next) = recurrence.surrounding(date);
+            [current, previous, next]
+                .into_iter()
+                .flatten()
+                .map(move |candidate| (index, candidate))
+        })
+        .min_by_key(|&(index, candidate)| {
+            let distance = (candidate - *date).num_days().abs();
+            (distance, candidate < *date, index)
+        })
+}
+
+/// Finds the dates within `start..=end` on which both recurrences occur.
+///
+/// This walks the occurrences of `a` and `b` side by side, always advancing whichever is
+/// behind, so it only visits the occurrences themselves rather than every day of the range.
+/// Useful for conflict detection, such as checking whether an every-Monday meeting ever falls
+/// on the 1st of the month.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::{coincidences_between, Recurrence};
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+/// let dates = coincidences_between(
+///     &Recurrence::Weekly(Weekday::Mon),
+///     &Recurrence::DayOfMonth(1),
+///     &start,
+///     &end,
+/// );
+/// assert_eq!(
+///     dates,
+///     [
+///         NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 7, 1).unwrap(),
+///     ]
+/// );
+/// ```
+pub fn coincidences_between(
+    a: &Recurrence,
+    b: &Recurrence,
+    start: &NaiveDate,
+    end: &NaiveDate,
+) -> Vec<NaiveDate> {
+    let mut next_a = a.align_forward(start);
+    let mut next_b = b.align_forward(start);
+    let mut dates = Vec::new();
+
+    while let (Some(date_a), Some(date_b)) = (next_a, next_b) {
+        if date_a > *end || date_b > *end {
+            break;
+        }
+        match date_a.cmp(&date_b) {
+            Ordering::Less => next_a = a.next(&date_a),
+            Ordering::Greater => next_b = b.next(&date_b),
+            Ordering::Equal => {
+                dates.push(date_a);
+                next_a = a.next(&date_a);
+                next_b = b.next(&date_b);
+            }
+        }
+    }
+
+    dates
+}
+
+/// Returns the first occurrence of an every-n-weeks recurrence on or after its anchor, and the
+/// number of days between occurrences, or `None` if `n` is 0.
+fn every_n_weeks_grid(weekday: Weekday, n: u32, anchor: &NaiveDate) -> Option<(NaiveDate, i64)> {
+    if n == 0 {
+        return None;
+    }
+    let offset = weekday.days_since(anchor.weekday());
+    let first = anchor.checked_add_days(Days::new(offset.into()))?;
+    Some((first, 7 * n as i64))
+}
+
+/// Returns a running count of proleptic Gregorian leap years up to and including `year`.
+///
+/// Only the difference between two calls is meaningful: it counts the leap years in between.
+fn leap_years_through(year: i32) -> i64 {
+    let year = year as i64;
+    year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400)
+}
+
+impl fmt::Display for Recurrence {
+    /// Formats the recurrence in the same compact form accepted by [`FromStr`], so that
+    /// `recurrence.to_string().parse()` yields the original recurrence.
+    ///
+    /// Exclusions chain on their base, so an excluded recurrence that is itself an exclusion is
+    /// wrapped in parentheses, as in `weekly:MON except (monthly:1 except weekly:MON)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Recurrence::Weekly(weekday) => write!(f, "weekly:{}", weekday_abbreviation(weekday)),
+            Recurrence::DayOfMonth(day) => write!(f, "monthly:{day}"),
+            Recurrence::AnnualDate { month, day } => write!(f, "annual:{month:02}-{day:02}"),
+            Recurrence::NthWeekdayOfMonth { weekday, n } => {
+                write!(f, "nth:{n}:{}", weekday_abbreviation(weekday))
+            }
+            Recurrence::EveryNWeeks { weekday, n, anchor } => {
+                write!(f, "weeks:{n}:{}:{anchor}", weekday_abbreviation(weekday))
+            }
+            Recurrence::Except {
+                ref base,
+                ref exclude,
+            } => match **exclude {
+                Recurrence::Except { .. } => write!(f, "{base} except ({exclude})"),
+                _ => write!(f, "{base} except {exclude}"),
+            },
+        }
+    }
+}
+
+/// Returns the upper-case three-letter abbreviation used by the string form of a recurrence.
+fn weekday_abbreviation(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MON",
+        Weekday::Tue => "TUE",
+        Weekday::Wed => "WED",
+        Weekday::Thu => "THU",
+        Weekday::Fri => "FRI",
+        Weekday::Sat => "SAT",
+        Weekday::Sun => "SUN",
+    }
+}
+
+/// An error returned when parsing a [`Recurrence`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRecurrenceError {
+    /// The input is missing the `:` separating the kind from its value.
+    MissingSeparator,
+    /// The kind before the `:` is not one of `weekly`, `monthly`, `annual`, `nth` or `weeks`.
+    UnknownKind(String),
+    /// The value of a `weekly` recurrence is not a weekday name.
+    InvalidWeekday(String),
+    /// The value of a `monthly` recurrence is not a day between 1 and 31.
+    InvalidDay(String),
+    /// The value of an `annual` recurrence is not a valid `MM-DD` date.
+    InvalidAnnualDate(String),
+    /// The value of an `nth` recurrence is not of the form `N:WEEKDAY`, with `N` between 1 and
+    /// 5.
+    InvalidNthWeekday(String),
+    /// The value of a `weeks` recurrence is not of the form `N:WEEKDAY:YYYY-MM-DD`, with `N`
+    /// at least 1.
+    InvalidEveryNWeeks(String),
+}
+
+impl fmt::Display for ParseRecurrenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRecurrenceError::MissingSeparator => {
+                write!(f, "expected a recurrence of the form `kind:value`")
+            }
+            ParseRecurrenceError::UnknownKind(kind) => write!(
+                f,
+                "unknown recurrence kind `{kind}`, expected `weekly`, `monthly`, `annual`, `nth` or `weeks`"
+            ),
+            ParseRecurrenceError::InvalidWeekday(value) => {
+                write!(f, "invalid weekday `{value}`")
+            }
+            ParseRecurrenceError::InvalidDay(value) => {
+                write!(f, "invalid day of the month `{value}`, expected 1-31")
+            }
+            ParseRecurrenceError::InvalidAnnualDate(value) => {
+                write!(f, "invalid annual date `{value}`, expected `MM-DD`")
+            }
+            ParseRecurrenceError::InvalidNthWeekday(value) => write!(
+                f,
+                "invalid nth-weekday recurrence `{value}`, expected `N:WEEKDAY` with N 1-5"
+            ),
+            ParseRecurrenceError::InvalidEveryNWeeks(value) => write!(
+                f,
+                "invalid every-n-weeks recurrence `{value}`, expected `N:WEEKDAY:YYYY-MM-DD`"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseRecurrenceError {}
+
+impl FromStr for Recurrence {
+    type Err = ParseRecurrenceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((base, exclude)) = split_last_except(s) {
+            return Ok(Recurrence::Except {
+                base: Box::new(base.parse()?),
+                exclude: Box::new(exclude.parse()?),
+            });
+        }
+        if let Some(group) = s.trim().strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+            return group.parse();
+        }
+
+        let (kind, value) = s
+            .trim()
+            .split_once(':')
+            .ok_or(ParseRecurrenceError::MissingSeparator)?;
+
+        match kind {
+            "weekly" => value
+                .parse::<Weekday>()
+                .map(Recurrence::Weekly)
+                .map_err(|_| ParseRecurrenceError::InvalidWeekday(value.to_string())),
+            "monthly" => match value.parse::<u32>() {
+                Ok(day) if (1..=31).contains(&day) => Ok(Recurrence::DayOfMonth(day)),
+                _ => Err(ParseRecurrenceError::InvalidDay(value.to_string())),
+            },
+            "annual" => {
+                let invalid = || ParseRecurrenceError::InvalidAnnualDate(value.to_string());
+                let (month, day) = value.split_once('-').ok_or_else(invalid)?;
+                let month = month.parse::<u32>().map_err(|_| invalid())?;
+                let day = day.parse::<u32>().map_err(|_| invalid())?;
+
+                // Validate against a leap year so that February 29 is accepted.
+                NaiveDate::from_ymd_opt(2000, month, day).ok_or_else(invalid)?;
+                Ok(Recurrence::AnnualDate { month, day })
+            }
+            "nth" => {
+                let invalid = || ParseRecurrenceError::InvalidNthWeekday(value.to_string());
+                let (n, weekday) = value.split_once(':').ok_or_else(invalid)?;
+                let n = n
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|n| (1..=5).contains(n))
+                    .ok_or_else(invalid)?;
+                let weekday = weekday.parse::<Weekday>().map_err(|_| invalid())?;
+                Ok(Recurrence::NthWeekdayOfMonth { weekday, n })
+            }
+            "weeks" => {
+                let invalid = || ParseRecurrenceError::InvalidEveryNWeeks(value.to_string());
+                let mut parts = value.splitn(3, ':');
+                let (Some(n), Some(weekday), Some(anchor)) =
+                    (parts.next(), parts.next(), parts.next())
+                else {
+                    return Err(invalid());
+                };
+                let n = n
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|n| *n >= 1)
+                    .ok_or_else(invalid)?;
+                let weekday = weekday.parse::<Weekday>().map_err(|_| invalid())?;
+                let anchor = anchor.parse::<NaiveDate>().map_err(|_| invalid())?;
+                Ok(Recurrence::EveryNWeeks { weekday, n, anchor })
+            }
+            _ => Err(ParseRecurrenceError::UnknownKind(kind.to_string())),
+        }
+    }
+}
+
+/// Finds the nth `weekday` of a month closest to `date`, strictly after it if `forward` is set
+/// and strictly before it otherwise.
+///
+/// Consecutive months with a 5th occurrence of a weekday are never more than 4 months apart, so
+/// the search covers the month of `date` and the 4 months after or before it.
+fn nearest_nth_weekday_of_month(
+    date: &NaiveDate,
+    weekday: Weekday,
+    n: u32,
+    forward: bool,
+) -> Option<NaiveDate> {
+    let month_start = date.with_day(1)?;
+    (0..=4)
+        .map_while(|i| {
+            if forward {
+                month_start.checked_add_months(Months::new(i))
+            } else {
+                month_start.checked_sub_months(Months::new(i))
+            }
+        })
+        .filter_map(|month| find_nth_weekday_of_month(month.year(), month.month(), weekday, n))
+        .find(|candidate| {
+            if forward {
+                candidate > date
+            } else {
+                candidate < date
+            }
+        })
+}
+
+/// Splits the string form of an exclusion at its last ` except ` outside of parentheses, so
+/// that exclusions chain on their base and a parenthesized exclusion stays grouped.
+fn split_last_except(s: &str) -> Option<(&str, &str)> {
+    const SEPARATOR: &str = " except ";
+    let mut depth = 0_i32;
+    let mut split = None;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ' ' if depth == 0 && s[i..].starts_with(SEPARATOR) => split = Some(i),
+            _ => {}
+        }
+    }
+    split.map(|i| (&s[..i], &s[i + SEPARATOR.len()..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recurrence_from_str() {
+        // Test case 1: Weekly recurrence with an abbreviated weekday
+        let result: Recurrence = "weekly:MON".parse().unwrap();
+        assert_eq!(result, Recurrence::Weekly(Weekday::Mon));
+
+        // Test case 2: Weekly recurrence with a full, lowercase weekday name
+        let result: Recurrence = "weekly:friday".parse().unwrap();
+        assert_eq!(result, Recurrence::Weekly(Weekday::Fri));
+
+        // Test case 3: Monthly recurrence
+        let result: Recurrence = "monthly:15".parse().unwrap();
+        assert_eq!(result, Recurrence::DayOfMonth(15));
+
+        // Test case 4: Annual recurrence on February 29
+        let result: Recurrence = "annual:02-29".parse().unwrap();
+        assert_eq!(result, Recurrence::AnnualDate { month: 2, day: 29 });
+
+        // Test case 5: Surrounding whitespace is ignored
+        let result: Recurrence = " annual:12-25\n".parse().unwrap();
+        assert_eq!(result, Recurrence::AnnualDate { month: 12, day: 25 });
+
+        // Test case 6: Every-n-weeks recurrence
+        let result: Recurrence = "weeks:2:MON:2024-01-01".parse().unwrap();
+        assert_eq!(
+            result,
+            Recurrence::EveryNWeeks {
+                weekday: Weekday::Mon,
+                n: 2,
+                anchor: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            }
+        );
+
+        // Test case 7: Nth weekday of the month
+        let result: Recurrence = "nth:1:MON".parse().unwrap();
+        assert_eq!(
+            result,
+            Recurrence::NthWeekdayOfMonth {
+                weekday: Weekday::Mon,
+                n: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_recurrence_from_str_malformed() {
+        // Test case 1: Missing separator
+        let result = "weekly".parse::<Recurrence>();
+        assert_eq!(result, Err(ParseRecurrenceError::MissingSeparator));
+
+        // Test case 2: Unknown kind
+        let result = "daily:1".parse::<Recurrence>();
+        assert_eq!(
+            result,
+            Err(ParseRecurrenceError::UnknownKind("daily".to_string()))
+        );
+
+        // Test case 3: Invalid weekday
+        let result = "weekly:funday".parse::<Recurrence>();
+        assert_eq!(
+            result,
+            Err(ParseRecurrenceError::InvalidWeekday("funday".to_string()))
+        );
+
+        // Test case 4: Day of the month out of range
+        let result = "monthly:32".parse::<Recurrence>();
+        assert_eq!(
+            result,
+            Err(ParseRecurrenceError::InvalidDay("32".to_string()))
+        );
+
+        // Test case 5: Day of the month is not a number
+        let result = "monthly:first".parse::<Recurrence>();
+        assert_eq!(
+            result,
+            Err(ParseRecurrenceError::InvalidDay("first".to_string()))
+        );
+
+        // Test case 6: Annual date that never exists
+        let result = "annual:02-30".parse::<Recurrence>();
+        assert_eq!(
+            result,
+            Err(ParseRecurrenceError::InvalidAnnualDate("02-30".to_string()))
+        );
+
+        // Test case 7: Annual date without a day
+        let result = "annual:02".parse::<Recurrence>();
+        assert_eq!(
+            result,
+            Err(ParseRecurrenceError::InvalidAnnualDate("02".to_string()))
+        );
+
+        // Test case 8: Every-n-weeks recurrence with a zero interval or missing anchor
+        for value in ["0:MON:2024-01-01", "2:MON", "2:MON:2024-02-30"] {
+            let result = format!("weeks:{value}").parse::<Recurrence>();
+            assert_eq!(
+                result,
+                Err(ParseRecurrenceError::InvalidEveryNWeeks(value.to_string()))
+            );
+        }
+
+        // Test case 9: Nth weekday of the month out of range or without a weekday
+        for value in ["0:MON", "6:MON", "1", "1:funday"] {
+            let result = format!("nth:{value}").parse::<Recurrence>();
+            assert_eq!(
+                result,
+                Err(ParseRecurrenceError::InvalidNthWeekday(value.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_recurrence_display() {
+        // Test case 1: Weekly
+        assert_eq!(Recurrence::Weekly(Weekday::Mon).to_string(), "weekly:MON");
+
+        // Test case 2: Day of month
+        assert_eq!(Recurrence::DayOfMonth(15).to_string(), "monthly:15");
+
+        // Test case 3: Annual date is zero-padded
+        let recurrence = Recurrence::AnnualDate { month: 2, day: 29 };
+        assert_eq!(recurrence.to_string(), "annual:02-29");
+
+        // Test case 4: Every n weeks
+        let recurrence = Recurrence::EveryNWeeks {
+            weekday: Weekday::Fri,
+            n: 3,
+            anchor: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+        };
+        assert_eq!(recurrence.to_string(), "weeks:3:FRI:2024-01-01");
+
+        // Test case 5: Nth weekday of the month
+        let recurrence = Recurrence::NthWeekdayOfMonth {
+            weekday: Weekday::Sun,
+            n: 2,
+        };
+        assert_eq!(recurrence.to_string(), "nth:2:SUN");
+    }
+
+    #[test]
+    fn test_recurrence_display_round_trip() {
+        let mut recurrences = Vec::new();
+        for weekday in [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ] {
+            recurrences.push(Recurrence::Weekly(weekday));
+        }
+        for day in 1..=31 {
+            recurrences.push(Recurrence::DayOfMonth(day));
+        }
+        // Every day of a leap year, including February 29.
+        let mut date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        while date.year() == 2024 {
+            recurrences.push(Recurrence::AnnualDate {
+                month: date.month(),
+                day: date.day(),
+            });
+            date = date.succ_opt().unwrap();
+        }
+        for n in [1, 2, 52] {
+            recurrences.push(Recurrence::EveryNWeeks {
+                weekday: Weekday::Wed,
+                n,
+                anchor: NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+            });
+        }
+        for n in 1..=5 {
+            recurrences.push(Recurrence::NthWeekdayOfMonth {
+                weekday: Weekday::Sat,
+                n,
+            });
+        }
+        let except = |base: Recurrence, exclude: Recurrence| Recurrence::Except {
+            base: Box::new(base),
+            exclude: Box::new(exclude),
+        };
+        let mondays_except_first =
+            except(Recurrence::Weekly(Weekday::Mon), Recurrence::DayOfMonth(1));
+        recurrences.push(mondays_except_first.clone());
+        recurrences.push(except(
+            mondays_except_first.clone(),
+            Recurrence::AnnualDate { month: 12, day: 25 },
+        ));
+        // An excluded recurrence that is itself an exclusion keeps its grouping.
+        let nested = except(
+            Recurrence::Weekly(Weekday::Mon),
+            except(Recurrence::DayOfMonth(1), Recurrence::Weekly(Weekday::Mon)),
+        );
+        assert_eq!(
+            nested.to_string(),
+            "weekly:MON except (monthly:1 except weekly:MON)"
+        );
+        recurrences.push(nested.clone());
+        recurrences.push(except(nested.clone(), nested));
+
+        for recurrence in recurrences {
+            let parsed: Recurrence = recurrence.to_string().parse().unwrap();
+            assert_eq!(parsed, recurrence);
+        }
+    }
+
+    #[test]
+    fn test_recurrence_count_occurrences() {
+        // Test case 1: Weekly recurrence over a 10-week span
+        let start = NaiveDate::from_ymd_opt(2023, 10, 2).unwrap(); // Monday
+        let end = NaiveDate::from_ymd_opt(2023, 12, 10).unwrap(); // Sunday, 10 weeks later
+        let recurrence = Recurrence::Weekly(Weekday::Wed);
+        assert_eq!(recurrence.count_occurrences(&start, &end), 10);
+
+        // Test case 2: Weekly recurrence where both ends of the range match
+        let end = NaiveDate::from_ymd_opt(2023, 12, 4).unwrap(); // Monday
+        let recurrence = Recurrence::Weekly(Weekday::Mon);
+        assert_eq!(recurrence.count_occurrences(&start, &end), 10);
+
+        // Test case 3: Day 31 over a year skips the short months
+        let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+        let recurrence = Recurrence::DayOfMonth(31);
+        assert_eq!(recurrence.count_occurrences(&start, &end), 7);
+
+        // Test case 4: Day of month with a partial first and last month
+        let start = NaiveDate::from_ymd_opt(2023, 1, 16).unwrap();
+        let end = NaiveDate::from_ymd_opt(2023, 3, 14).unwrap();
+        let recurrence = Recurrence::DayOfMonth(15);
+        assert_eq!(recurrence.count_occurrences(&start, &end), 1);
+
+        // Test case 5: February 29 over 10 years
+        let start = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2029, 12, 31).unwrap();
+        let recurrence = Recurrence::AnnualDate { month: 2, day: 29 };
+        assert_eq!(recurrence.count_occurrences(&start, &end), 3);
+
+        // Test case 6: February 29 where the first leap day is before the start
+        let start = NaiveDate::from_ymd_opt(2020, 3, 1).unwrap();
+        assert_eq!(recurrence.count_occurrences(&start, &end), 2);
+
+        // Test case 7: Annual date where the last occurrence is after the end
+        let start = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2029, 12, 24).unwrap();
+        let recurrence = Recurrence::AnnualDate { month: 12, day: 25 };
+        assert_eq!(recurrence.count_occurrences(&start, &end), 9);
+
+        // Test case 8: An empty range
+        assert_eq!(recurrence.count_occurrences(&end, &start), 0);
+    }
+
+    #[test]
+    fn test_recurrence_surrounding() {
+        let date = NaiveDate::from_ymd_opt(2023, 10, 18).unwrap(); // Wednesday
+
+        // Test case 1: Weekly
+        let (previous, next) = Recurrence::Weekly(Weekday::Mon).surrounding(&date);
+        assert_eq!(previous, NaiveDate::from_ymd_opt(2023, 10, 16));
+        assert_eq!(next, NaiveDate::from_ymd_opt(2023, 10, 23));
+
+        // Test case 2: Day of month, skipping short months in both directions
+        let date = NaiveDate::from_ymd_opt(2023, 2, 15).unwrap();
+        let (previous, next) = Recurrence::DayOfMonth(31).surrounding(&date);
+        assert_eq!(previous, NaiveDate::from_ymd_opt(2023, 1, 31));
+        assert_eq!(next, NaiveDate::from_ymd_opt(2023, 3, 31));
+
+        // Test case 3: Annual date on February 29
+        let date = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        let (previous, next) = Recurrence::AnnualDate { month: 2, day: 29 }.surrounding(&date);
+        assert_eq!(previous, NaiveDate::from_ymd_opt(2024, 2, 29));
+        assert_eq!(next, NaiveDate::from_ymd_opt(2028, 2, 29));
+
+        // Test case 4: A matching date is bracketed by its neighbours, not returned itself
+        let date = NaiveDate::from_ymd_opt(2023, 12, 25).unwrap();
+        let (previous, next) = Recurrence::AnnualDate { month: 12, day: 25 }.surrounding(&date);
+        assert_eq!(previous, NaiveDate::from_ymd_opt(2022, 12, 25));
+        assert_eq!(next, NaiveDate::from_ymd_opt(2024, 12, 25));
+    }
+
+    #[test]
+    fn test_recurrence_occurrences_in_month() {
+        // Test case 1: Weekly recurrence in a month with five of the weekday
+        let result = Recurrence::Weekly(Weekday::Tue).occurrences_in_month(2023, 10);
+        let expected: Vec<_> = [3, 10, 17, 24, 31]
+            .into_iter()
+            .map(|day| NaiveDate::from_ymd_opt(2023, 10, day).unwrap())
+            .collect();
+        assert_eq!(result, expected);
+
+        // Test case 2: Weekly recurrence in a month with four of the weekday
+        let result = Recurrence::Weekly(Weekday::Mon).occurrences_in_month(2023, 2);
+        let expected: Vec<_> = [6, 13, 20, 27]
+            .into_iter()
+            .map(|day| NaiveDate::from_ymd_opt(2023, 2, day).unwrap())
+            .collect();
+        assert_eq!(result, expected);
+
+        // Test case 3: Day of month that exists in the month
+        let result = Recurrence::DayOfMonth(15).occurrences_in_month(2023, 2);
+        assert_eq!(result, [NaiveDate::from_ymd_opt(2023, 2, 15).unwrap()]);
+
+        // Test case 4: Day of month that does not exist in the month
+        let result = Recurrence::DayOfMonth(31).occurrences_in_month(2023, 4);
+        assert!(result.is_empty());
+
+        // Test case 5: Annual date in its month of a leap year
+        let recurrence = Recurrence::AnnualDate { month: 2, day: 29 };
+        let result = recurrence.occurrences_in_month(2024, 2);
+        assert_eq!(result, [NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()]);
+
+        // Test case 6: Annual date in its month of a non-leap year
+        assert!(recurrence.occurrences_in_month(2023, 2).is_empty());
+
+        // Test case 7: Annual date in another month
+        assert!(recurrence.occurrences_in_month(2024, 3).is_empty());
+
+        // Test case 8: Invalid month
+        assert!(Recurrence::DayOfMonth(1)
+            .occurrences_in_month(2024, 13)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_recurrence_occurrences() {
+        // Test case 1: Day 31 skips the short months
+        let from = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let result: Vec<_> = Recurrence::DayOfMonth(31)
+            .occurrences(&from)
+            .take(4)
+            .collect();
+        let expected: Vec<_> = [(1, 31), (3, 31), (5, 31), (7, 31)]
+            .into_iter()
+            .map(|(month, day)| NaiveDate::from_ymd_opt(2023, month, day).unwrap())
+            .collect();
+        assert_eq!(result, expected);
+
+        // Test case 2: The iterator ends when the dates overflow
+        let recurrence = Recurrence::Weekly(Weekday::Mon);
+        assert_eq!(recurrence.occurrences(&NaiveDate::MAX).next(), None);
+    }
+
+    #[test]
+    fn test_recurrence_cursor() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+        let from = date(1, 15);
+        let mut cursor = RecurrenceCursor::new(Recurrence::DayOfMonth(31), from);
+
+        // Peeking does not move the cursor.
+        assert_eq!(cursor.peek(), Some(date(1, 31)));
+        assert_eq!(cursor.peek(), Some(date(1, 31)));
+
+        // Advancing skips the months without a 31st.
+        assert_eq!(cursor.advance(), Some(date(1, 31)));
+        assert_eq!(cursor.advance(), Some(date(3, 31)));
+        assert_eq!(cursor.peek(), Some(date(5, 31)));
+        assert_eq!(cursor.advance(), Some(date(5, 31)));
+        assert_eq!(cursor.advance(), Some(date(7, 31)));
+        assert_eq!(cursor.advance(), Some(date(8, 31)));
+        assert_eq!(cursor.advance(), Some(date(10, 31)));
+
+        // The cursor can be used as an iterator with early termination.
+        let rest: Vec<_> = cursor.take_while(|d| d.year() == 2023).collect();
+        assert_eq!(rest, [date(12, 31)]);
+
+        // An exhausted cursor keeps returning None.
+        let mut cursor = RecurrenceCursor::new(Recurrence::Weekly(Weekday::Mon), NaiveDate::MAX);
+        assert_eq!(cursor.peek(), None);
+        assert_eq!(cursor.advance(), None);
+        assert_eq!(cursor.advance(), None);
+    }
+
+    #[test]
+    fn test_merge_next() {
+        let recurrences = vec![Recurrence::Weekly(Weekday::Mon), Recurrence::DayOfMonth(1)];
+        let from = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // Sunday
+        let merged: Vec<_> = merge_next(recurrences, from).take(10).collect();
+
+        let expected = [
+            (0, NaiveDate::from_ymd_opt(2023, 10, 16).unwrap()),
+            (0, NaiveDate::from_ymd_opt(2023, 10, 23).unwrap()),
+            (0, NaiveDate::from_ymd_opt(2023, 10, 30).unwrap()),
+            (1, NaiveDate::from_ymd_opt(2023, 11, 1).unwrap()),
+            (0, NaiveDate::from_ymd_opt(2023, 11, 6).unwrap()),
+            (0, NaiveDate::from_ymd_opt(2023, 11, 13).unwrap()),
+            (0, NaiveDate::from_ymd_opt(2023, 11, 20).unwrap()),
+            (0, NaiveDate::from_ymd_opt(2023, 11, 27).unwrap()),
+            (1, NaiveDate::from_ymd_opt(2023, 12, 1).unwrap()),
+            (0, NaiveDate::from_ymd_opt(2023, 12, 4).unwrap()),
+        ];
+        assert_eq!(merged, expected);
+
+        // The merged stream is in global chronological order.
+        assert!(merged.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+    }
+
+    #[test]
+    fn test_merge_next_same_date() {
+        // January 1st, 2024 is a Monday, so both recurrences fire; the lower index comes first.
+        let recurrences = vec![Recurrence::DayOfMonth(1), Recurrence::Weekly(Weekday::Mon)];
+        let from = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+        let merged: Vec<_> = merge_next(recurrences, from).take(2).collect();
+        let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        assert_eq!(merged, [(0, date), (1, date)]);
+
+        // An empty list of recurrences yields nothing.
+        assert_eq!(merge_next(Vec::new(), from).next(), None);
+    }
+
+    #[test]
+    fn test_recurrence_step_between() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: Weekly, correctly and incorrectly spaced
+        let recurrence = Recurrence::Weekly(Weekday::Mon);
+        let step = recurrence.step_between(&date(2023, 10, 16), &date(2023, 10, 23));
+        assert_eq!(step, Some(RecurrenceStep::Days(7)));
+        assert_eq!(
+            recurrence.step_between(&date(2023, 10, 16), &date(2023, 10, 30)),
+            None
+        );
+        assert_eq!(
+            recurrence.step_between(&date(2023, 10, 17), &date(2023, 10, 23)),
+            None
+        );
+
+        // Test case 2: Day of month, one month apart
+        let recurrence = Recurrence::DayOfMonth(15);
+        let step = recurrence.step_between(&date(2023, 12, 15), &date(2024, 1, 15));
+        assert_eq!(step, Some(RecurrenceStep::Months(1)));
+        assert_eq!(
+            recurrence.step_between(&date(2023, 12, 15), &date(2024, 2, 15)),
+            None
+        );
+
+        // Test case 3: Day 31 skipping February
+        let recurrence = Recurrence::DayOfMonth(31);
+        let step = recurrence.step_between(&date(2023, 1, 31), &date(2023, 3, 31));
+        assert_eq!(step, Some(RecurrenceStep::Months(2)));
+
+        // Test case 4: Annual date
+        let recurrence = Recurrence::AnnualDate { month: 12, day: 25 };
+        let step = recurrence.step_between(&date(2023, 12, 25), &date(2024, 12, 25));
+        assert_eq!(step, Some(RecurrenceStep::Years(1)));
+        assert_eq!(
+            recurrence.step_between(&date(2023, 12, 25), &date(2023, 12, 25)),
+            None
+        );
+
+        // Test case 5: February 29 steps between leap years
+        let recurrence = Recurrence::AnnualDate { month: 2, day: 29 };
+        let step = recurrence.step_between(&date(2024, 2, 29), &date(2028, 2, 29));
+        assert_eq!(step, Some(RecurrenceStep::Years(4)));
+        assert_eq!(
+            recurrence.step_between(&date(2024, 2, 29), &date(2032, 2, 29)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_recurrence_validate() {
+        // Test case 1: Valid recurrences
+        assert_eq!(Recurrence::Weekly(Weekday::Sun).validate(), Ok(()));
+        assert_eq!(Recurrence::DayOfMonth(1).validate(), Ok(()));
+        assert_eq!(Recurrence::DayOfMonth(31).validate(), Ok(()));
+        assert_eq!(
+            Recurrence::AnnualDate { month: 2, day: 29 }.validate(),
+            Ok(())
+        );
+        assert_eq!(
+            Recurrence::AnnualDate { month: 12, day: 31 }.validate(),
+            Ok(())
+        );
+
+        // Test case 2: Day of month out of range
+        assert_eq!(
+            Recurrence::DayOfMonth(0).validate(),
+            Err(NextDayError::InvalidDay(0))
+        );
+        assert_eq!(
+            Recurrence::DayOfMonth(32).validate(),
+            Err(NextDayError::InvalidDay(32))
+        );
+
+        // Test case 3: Annual date with an invalid month
+        assert_eq!(
+            Recurrence::AnnualDate { month: 0, day: 1 }.validate(),
+            Err(NextDayError::InvalidMonth(0))
+        );
+        assert_eq!(
+            Recurrence::AnnualDate { month: 13, day: 1 }.validate(),
+            Err(NextDayError::InvalidMonth(13))
+        );
+
+        // Test case 4: Annual date with a day that never occurs in its month
+        assert_eq!(
+            Recurrence::AnnualDate { month: 2, day: 30 }.validate(),
+            Err(NextDayError::InvalidDay(30))
+        );
+        assert_eq!(
+            Recurrence::AnnualDate { month: 4, day: 31 }.validate(),
+            Err(NextDayError::InvalidDay(31))
+        );
+        assert_eq!(
+            Recurrence::AnnualDate { month: 1, day: 0 }.validate(),
+            Err(NextDayError::InvalidDay(0))
+        );
+
+        // Test case 5: Every-n-weeks recurrence with a zero interval
+        let anchor = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let recurrence = Recurrence::EveryNWeeks {
+            weekday: Weekday::Mon,
+            n: 1,
+            anchor,
+        };
+        assert_eq!(recurrence.validate(), Ok(()));
+        let recurrence = Recurrence::EveryNWeeks {
+            weekday: Weekday::Mon,
+            n: 0,
+            anchor,
+        };
+        assert_eq!(recurrence.validate(), Err(NextDayError::InvalidInterval(0)));
+    }
+
+    #[test]
+    fn test_recurrence_matches() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(); // Thursday
+
+        assert!(Recurrence::Weekly(Weekday::Thu).matches(&date));
+        assert!(!Recurrence::Weekly(Weekday::Fri).matches(&date));
+        assert!(Recurrence::DayOfMonth(29).matches(&date));
+        assert!(!Recurrence::DayOfMonth(28).matches(&date));
+        assert!(Recurrence::AnnualDate { month: 2, day: 29 }.matches(&date));
+        assert!(!Recurrence::AnnualDate { month: 3, day: 29 }.matches(&date));
+    }
+
+    #[test]
+    fn test_recurrence_align_forward() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: Weekly
+        let recurrence = Recurrence::Weekly(Weekday::Mon);
+        assert_eq!(
+            recurrence.align_forward(&date(2023, 10, 16)),
+            Some(date(2023, 10, 16))
+        );
+        assert_eq!(
+            recurrence.align_forward(&date(2023, 10, 17)),
+            Some(date(2023, 10, 23))
+        );
+
+        // Test case 2: Day of month
+        let recurrence = Recurrence::DayOfMonth(31);
+        assert_eq!(
+            recurrence.align_forward(&date(2023, 1, 31)),
+            Some(date(2023, 1, 31))
+        );
+        assert_eq!(
+            recurrence.align_forward(&date(2023, 2, 1)),
+            Some(date(2023, 3, 31))
+        );
+
+        // Test case 3: Annual date
+        let recurrence = Recurrence::AnnualDate { month: 2, day: 29 };
+        assert_eq!(
+            recurrence.align_forward(&date(2024, 2, 29)),
+            Some(date(2024, 2, 29))
+        );
+        assert_eq!(
+            recurrence.align_forward(&date(2024, 3, 1)),
+            Some(date(2028, 2, 29))
+        );
+    }
+
+    #[test]
+    fn test_recurrence_next() {
+        let date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap(); // Sunday
+
+        // Test case 1: Weekly
+        let result = Recurrence::Weekly(Weekday::Mon).next(&date).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 10, 16).unwrap());
+
+        // Test case 2: Day of month
+        let result = Recurrence::DayOfMonth(10).next(&date).unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2023, 11, 10).unwrap());
+
+        // Test case 3: Annual date
+        let result = Recurrence::AnnualDate { month: 2, day: 29 }
+            .next(&date)
+            .unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+    }
+
+    #[test]
+    fn test_recurrence_every_n_weeks() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: n = 1 is the same as a plain weekly recurrence
+        let every_week = Recurrence::EveryNWeeks {
+            weekday: Weekday::Thu,
+            n: 1,
+            anchor: date(2020, 6, 1),
+        };
+        let weekly = Recurrence::Weekly(Weekday::Thu);
+        let mut current = date(2023, 12, 20);
+        while current <= date(2024, 3, 10) {
+            assert_eq!(every_week.next(&current), weekly.next(&current));
+            assert_eq!(every_week.matches(&current), weekly.matches(&current));
+            current = current.succ_opt().unwrap();
+        }
+
+        // Test case 2: n = 2 skips the off-phase weeks
+        let biweekly = Recurrence::EveryNWeeks {
+            weekday: Weekday::Mon,
+            n: 2,
+            anchor: date(2024, 1, 1), // Monday
+        };
+        assert_eq!(biweekly.next(&date(2023, 12, 31)), Some(date(2024, 1, 1)));
+        assert_eq!(biweekly.next(&date(2024, 1, 1)), Some(date(2024, 1, 15)));
+        assert_eq!(biweekly.next(&date(2024, 1, 8)), Some(date(2024, 1, 15)));
+        assert!(!biweekly.matches(&date(2024, 1, 8)));
+        assert!(biweekly.matches(&date(2023, 12, 18)));
+
+        // Test case 3: n = 3 across a phase boundary, with an anchor that is not on the weekday
+        let every_third = Recurrence::EveryNWeeks {
+            weekday: Weekday::Fri,
+            n: 3,
+            anchor: date(2024, 1, 1), // Monday, so the first occurrence is Friday 2024-01-05
+        };
+        assert_eq!(every_third.next(&date(2024, 1, 4)), Some(date(2024, 1, 5)));
+        assert_eq!(every_third.next(&date(2024, 1, 5)), Some(date(2024, 1, 26)));
+        assert_eq!(
+            every_third.next(&date(2024, 1, 25)),
+            Some(date(2024, 1, 26))
+        );
+        assert_eq!(
+            every_third.next(&date(2024, 1, 26)),
+            Some(date(2024, 2, 16))
+        );
+        // Dates before the anchor stay in phase.
+        assert_eq!(
+            every_third.next(&date(2023, 12, 1)),
+            Some(date(2023, 12, 15))
+        );
+        assert_eq!(
+            every_third.surrounding(&date(2024, 2, 1)),
+            (Some(date(2024, 1, 26)), Some(date(2024, 2, 16)))
+        );
+        assert_eq!(
+            every_third.count_occurrences(&date(2024, 1, 1), &date(2024, 3, 8)),
+            4
+        );
+        assert_eq!(
+            every_third.occurrences_in_month(2024, 3),
+            vec![date(2024, 3, 8), date(2024, 3, 29)]
+        );
+
+        // Test case 4: n = 0 never occurs
+        let never = Recurrence::EveryNWeeks {
+            weekday: Weekday::Mon,
+            n: 0,
+            anchor: date(2024, 1, 1),
+        };
+        assert_eq!(never.next(&date(2023, 12, 31)), None);
+        assert!(!never.matches(&date(2024, 1, 1)));
+    }
+
+    #[test]
+    fn test_recurrence_next_always_advances() {
+        let anchor = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let recurrences = [
+            Recurrence::Weekly(Weekday::Mon),
+            Recurrence::Weekly(Weekday::Sun),
+            Recurrence::DayOfMonth(1),
+            Recurrence::DayOfMonth(29),
+            Recurrence::DayOfMonth(31),
+            Recurrence::AnnualDate { month: 1, day: 1 },
+            Recurrence::AnnualDate { month: 2, day: 29 },
+            Recurrence::AnnualDate { month: 12, day: 31 },
+            Recurrence::EveryNWeeks {
+                weekday: Weekday::Thu,
+                n: 2,
+                anchor,
+            },
+            Recurrence::EveryNWeeks {
+                weekday: Weekday::Sat,
+                n: 5,
+                anchor,
+            },
+            Recurrence::NthWeekdayOfMonth {
+                weekday: Weekday::Mon,
+                n: 1,
+            },
+            Recurrence::NthWeekdayOfMonth {
+                weekday: Weekday::Fri,
+                n: 5,
+            },
+        ];
+
+        let mut current = NaiveDate::from_ymd_opt(2023, 12, 1).unwrap();
+        while current <= NaiveDate::from_ymd_opt(2025, 3, 31).unwrap() {
+            for recurrence in &recurrences {
+                let first = recurrence.next(&current).unwrap();
+                let second = recurrence.next(&first).unwrap();
+                assert!(first > current, "{recurrence} from {current}");
+                assert!(second > first, "{recurrence} from {first}");
+            }
+            current = current.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_coincidences_between() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let mondays = Recurrence::Weekly(Weekday::Mon);
+        let firsts = Recurrence::DayOfMonth(1);
+
+        // Test case 1: In the quarter from January 1st, both ends fall on a Monday the 1st
+        let result = coincidences_between(&mondays, &firsts, &date(2024, 1, 1), &date(2024, 4, 1));
+        assert_eq!(result, vec![date(2024, 1, 1), date(2024, 4, 1)]);
+
+        // Test case 2: The order of the recurrences does not matter
+        let result = coincidences_between(&firsts, &mondays, &date(2024, 1, 1), &date(2024, 4, 1));
+        assert_eq!(result, vec![date(2024, 1, 1), date(2024, 4, 1)]);
+
+        // Test case 3: A quarter without a Monday the 1st
+        let result = coincidences_between(&mondays, &firsts, &date(2024, 1, 2), &date(2024, 3, 31));
+        assert!(result.is_empty());
+
+        // Test case 4: Recurrences that never coincide
+        let result = coincidences_between(
+            &Recurrence::DayOfMonth(31),
+            &Recurrence::AnnualDate { month: 2, day: 29 },
+            &date(2020, 1, 1),
+            &date(2030, 1, 1),
+        );
+        assert!(result.is_empty());
+
+        // Test case 5: An empty range
+        let result = coincidences_between(&mondays, &firsts, &date(2024, 1, 2), &date(2024, 1, 1));
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_recurrence_next_with_weekday() {
+        // Test case 1: Day of month over a year, the weekday always matches the date
+        let recurrence = Recurrence::DayOfMonth(31);
+        let mut from = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        for _ in 0..7 {
+            let (date, weekday) = recurrence.next_with_weekday(&from).unwrap();
+            assert_eq!(Some(date), recurrence.next(&from));
+            assert_eq!(weekday, date.weekday());
+            from = date;
+        }
+
+        // Test case 2: A known date
+        let from = NaiveDate::from_ymd_opt(2023, 12, 1).unwrap();
+        let result = Recurrence::AnnualDate { month: 12, day: 25 }.next_with_weekday(&from);
+        assert_eq!(
+            result,
+            Some((NaiveDate::from_ymd_opt(2023, 12, 25).unwrap(), Weekday::Mon))
+        );
+
+        // Test case 3: No occurrence
+        let result = Recurrence::DayOfMonth(32).next_with_weekday(&from);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_next_across() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let candidates = [Recurrence::DayOfMonth(1), Recurrence::Weekly(Weekday::Mon)];
+
+        // Test case 1: The weekday comes first
+        let result = next_across(&candidates, &date(2023, 10, 15));
+        assert_eq!(result, Some((1, date(2023, 10, 16))));
+
+        // Test case 2: The day of the month comes first
+        let result = next_across(&candidates, &date(2023, 10, 31));
+        assert_eq!(result, Some((0, date(2023, 11, 1))));
+
+        // Test case 3: Both fall on the same date, so the lower index wins
+        let result = next_across(&candidates, &date(2023, 12, 31));
+        assert_eq!(result, Some((0, date(2024, 1, 1))));
+
+        // Test case 4: Invalid candidates are skipped, and no candidates give no date
+        let candidates = [Recurrence::DayOfMonth(32), Recurrence::DayOfMonth(20)];
+        let result = next_across(&candidates, &date(2023, 10, 15));
+        assert_eq!(result, Some((1, date(2023, 10, 20))));
+        assert_eq!(next_across(&[], &date(2023, 10, 15)), None);
+    }
+
+    #[test]
+    fn test_nearest_across() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+        let recurrences = [Recurrence::Weekly(Weekday::Mon), Recurrence::DayOfMonth(15)];
+
+        // Test case 1: The nearest occurrence is in the past
+        let result = nearest_across(&recurrences, &date(8, 16)); // A Wednesday
+        assert_eq!(result, Some((1, date(8, 15))));
+        let result = nearest_across(&recurrences, &date(10, 18)); // A Wednesday
+        assert_eq!(result, Some((0, date(10, 16))));
+
+        // Test case 2: The nearest occurrence is in the future
+        let result = nearest_across(&recurrences, &date(10, 21)); // A Saturday
+        assert_eq!(result, Some((0, date(10, 23))));
+
+        // Test case 3: A date that is an occurrence snaps to itself
+        let result = nearest_across(&recurrences, &date(10, 30)); // A Monday
+        assert_eq!(result, Some((0, date(10, 30))));
+
+        // Test case 4: Equally far occurrences in both directions prefer the future
+        let recurrences = [Recurrence::Weekly(Weekday::Tue), Recurrence::DayOfMonth(19)];
+        let result = nearest_across(&recurrences, &date(10, 18)); // A Wednesday
+        assert_eq!(result, Some((1, date(10, 19))));
+        let result = nearest_across(&recurrences[..1], &date(10, 13)); // A Friday
+        assert_eq!(result, Some((0, date(10, 10))));
+
+        // Test case 5: Invalid recurrences are skipped, and no recurrences give no date
+        let recurrences = [Recurrence::DayOfMonth(32), Recurrence::DayOfMonth(20)];
+        let result = nearest_across(&recurrences, &date(10, 15));
+        assert_eq!(result, Some((1, date(10, 20))));
+        assert_eq!(nearest_across(&[], &date(10, 15)), None);
+    }
+
+    #[test]
+    fn test_next_for_each() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let recurrences = [
+            Recurrence::Weekly(Weekday::Fri),
+            Recurrence::DayOfMonth(31),
+            Recurrence::AnnualDate { month: 2, day: 29 },
+            Recurrence::AnnualDate { month: 12, day: 25 },
+            Recurrence::DayOfMonth(32),
+        ];
+
+        // Test case 1: Each rule advances independently, in the order given
+        let result = next_for_each(&recurrences, &date(2024, 4, 1));
+        assert_eq!(
+            result,
+            [
+                Some(date(2024, 4, 5)),
+                Some(date(2024, 5, 31)),
+                Some(date(2028, 2, 29)),
+                Some(date(2024, 12, 25)),
+                None,
+            ]
+        );
+
+        // Test case 2: Every entry agrees with the recurrence's own next occurrence
+        let from = date(2023, 12, 31);
+        let result = next_for_each(&recurrences, &from);
+        for (recurrence, next) in recurrences.iter().zip(result) {
+            assert_eq!(next, recurrence.next(&from));
+        }
+
+        // Test case 3: No rules give no results
+        assert!(next_for_each(&[], &from).is_empty());
+    }
+
+    #[test]
+    fn test_infer_recurrence() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: The same month and day is annual, even on different weekdays
+        let result = infer_recurrence(&date(2023, 12, 25), &date(2024, 12, 25));
+        assert_eq!(result, Some(Recurrence::AnnualDate { month: 12, day: 25 }));
+        let result = infer_recurrence(&date(2020, 2, 29), &date(2024, 2, 29));
+        assert_eq!(result, Some(Recurrence::AnnualDate { month: 2, day: 29 }));
+
+        // Test case 2: The same day of the month is monthly, in either order
+        let result = infer_recurrence(&date(2023, 3, 31), &date(2023, 1, 31));
+        assert_eq!(result, Some(Recurrence::DayOfMonth(31)));
+
+        // Test case 3: The same weekday is weekly
+        let result = infer_recurrence(&date(2023, 10, 16), &date(2023, 11, 20));
+        assert_eq!(result, Some(Recurrence::Weekly(Weekday::Mon)));
+
+        // Test case 4: Monthly is preferred over weekly when both fit
+        let (a, b) = (date(2024, 1, 1), date(2024, 4, 1));
+        assert_eq!(a.weekday(), b.weekday());
+        assert_eq!(infer_recurrence(&a, &b), Some(Recurrence::DayOfMonth(1)));
+
+        // Test case 5: Annual is preferred over weekly when both fit
+        let (a, b) = (date(2018, 7, 4), date(2029, 7, 4));
+        assert_eq!(a.weekday(), b.weekday());
+        let result = infer_recurrence(&a, &b);
+        assert_eq!(result, Some(Recurrence::AnnualDate { month: 7, day: 4 }));
+
+        // Test case 6: The inferred recurrence has both dates as occurrences
+        let a = date(2023, 10, 16);
+        for b in crate::dates_in_range(&date(2023, 1, 1), &date(2024, 12, 31)) {
+            if let Some(recurrence) = infer_recurrence(&a, &b) {
+                assert!(recurrence.matches(&a) && recurrence.matches(&b));
+            }
+        }
+
+        // Test case 7: Nothing fits, or a single date fits everything
+        assert_eq!(infer_recurrence(&a, &date(2023, 10, 17)), None);
+        assert_eq!(infer_recurrence(&a, &a), None);
+    }
+
+    #[test]
+    fn test_find_next_first_of() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let candidates = [Recurrence::DayOfMonth(1), Recurrence::Weekly(Weekday::Mon)];
+
+        // Test case 1: A day-of-month and a weekday candidate, each winning once
+        assert_eq!(
+            find_next_first_of(&candidates, &date(2023, 10, 15)),
+            Some(date(2023, 10, 16))
+        );
+        assert_eq!(
+            find_next_first_of(&candidates, &date(2023, 10, 31)),
+            Some(date(2023, 11, 1))
+        );
+
+        // Test case 2: No candidates
+        assert_eq!(find_next_first_of(&[], &date(2023, 10, 15)), None);
+    }
+
+    #[test]
+    fn test_recurrence_count_occurrences_until() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+        let recurrence = Recurrence::Weekly(Weekday::Mon);
+
+        // Test case 1: From a Monday, that Monday itself is not counted
+        assert_eq!(
+            recurrence.count_occurrences_until(&date(10, 16), &date(11, 6)),
+            3
+        );
+
+        // Test case 2: A deadline between occurrences
+        assert_eq!(
+            recurrence.count_occurrences_until(&date(10, 15), &date(11, 5)),
+            3
+        );
+
+        // Test case 3: A deadline on an occurrence is counted
+        assert_eq!(
+            recurrence.count_occurrences_until(&date(10, 15), &date(11, 6)),
+            4
+        );
+
+        // Test case 4: A deadline on or before the start
+        assert_eq!(
+            recurrence.count_occurrences_until(&date(10, 16), &date(10, 16)),
+            0
+        );
+        assert_eq!(
+            recurrence.count_occurrences_until(&date(10, 16), &date(10, 1)),
+            0
+        );
+        assert_eq!(
+            recurrence.count_occurrences_until(&NaiveDate::MAX, &NaiveDate::MAX),
+            0
+        );
+    }
+
+    #[test]
+    fn test_recurrence_prev_occurrence() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let recurrences = [
+            Recurrence::Weekly(Weekday::Mon),
+            Recurrence::DayOfMonth(15),
+            Recurrence::DayOfMonth(31),
+            Recurrence::AnnualDate { month: 2, day: 29 },
+            Recurrence::AnnualDate { month: 12, day: 25 },
+            Recurrence::EveryNWeeks {
+                weekday: Weekday::Fri,
+                n: 3,
+                anchor: date(2024, 1, 1),
+            },
+        ];
+
+        // Test case 1: Stepping back from an occurrence and forward again returns to it
+        for recurrence in recurrences {
+            let mut occurrence = recurrence.align_forward(&date(2023, 1, 1)).unwrap();
+            for _ in 0..10 {
+                let previous = recurrence.prev_occurrence(&occurrence).unwrap();
+                assert!(previous < occurrence);
+                assert!(recurrence.matches(&previous));
+                assert_eq!(recurrence.next(&previous), Some(occurrence), "{recurrence}");
+                occurrence = recurrence.next(&occurrence).unwrap();
+            }
+        }
+
+        // Test case 2: From a date that is not an occurrence
+        let recurrence = Recurrence::DayOfMonth(31);
+        assert_eq!(
+            recurrence.prev_occurrence(&date(2023, 3, 15)),
+            Some(date(2023, 1, 31))
+        );
+        let recurrence = Recurrence::AnnualDate { month: 2, day: 29 };
+        assert_eq!(
+            recurrence.prev_occurrence(&date(2027, 6, 1)),
+            Some(date(2024, 2, 29))
+        );
+    }
+
+    #[test]
+    fn test_recurrence_occurrence_index() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+        let recurrence = Recurrence::Weekly(Weekday::Mon);
+        let anchor = date(10, 2); // Monday
+
+        // Test case 1: Each following Monday has the next index
+        let mut monday = anchor;
+        for expected in 1..=8 {
+            assert_eq!(
+                recurrence.occurrence_index(&anchor, &monday),
+                Some(expected)
+            );
+            monday = recurrence.next(&monday).unwrap();
+        }
+
+        // Test case 2: A date between occurrences counts the occurrences so far
+        assert_eq!(recurrence.occurrence_index(&anchor, &date(10, 15)), Some(2));
+
+        // Test case 3: An anchor that is not an occurrence
+        assert_eq!(
+            recurrence.occurrence_index(&date(10, 3), &date(10, 9)),
+            Some(1)
+        );
+        assert_eq!(
+            recurrence.occurrence_index(&date(10, 3), &date(10, 8)),
+            Some(0)
+        );
+
+        // Test case 4: Annual recurrences count years
+        let recurrence = Recurrence::AnnualDate { month: 10, day: 2 };
+        let date_2030 = NaiveDate::from_ymd_opt(2030, 10, 2).unwrap();
+        assert_eq!(recurrence.occurrence_index(&anchor, &date_2030), Some(8));
+
+        // Test case 5: A date before the anchor
+        assert_eq!(recurrence.occurrence_index(&anchor, &date(10, 1)), None);
+    }
+
+    #[test]
+    fn test_recurrence_occurrences_by_month() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+
+        // Test case 1: Weekly over 2 months, starting mid-month
+        let grouped = Recurrence::Weekly(Weekday::Mon).occurrences_by_month(&date(10, 16), 2);
+        assert_eq!(
+            grouped,
+            vec![
+                (2023, 10, vec![date(10, 23), date(10, 30)]),
+                (
+                    2023,
+                    11,
+                    vec![date(11, 6), date(11, 13), date(11, 20), date(11, 27)]
+                ),
+            ]
+        );
+
+        // Test case 2: Day 31 has empty months, which are kept
+        let grouped = Recurrence::DayOfMonth(31).occurrences_by_month(&date(4, 1), 4);
+        assert_eq!(
+            grouped,
+            vec![
+                (2023, 4, vec![]),
+                (2023, 5, vec![date(5, 31)]),
+                (2023, 6, vec![]),
+                (2023, 7, vec![date(7, 31)]),
+            ]
+        );
+
+        // Test case 3: Crossing into the next year
+        let grouped = Recurrence::DayOfMonth(1).occurrences_by_month(&date(12, 1), 2);
+        let january = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        assert_eq!(grouped, vec![(2023, 12, vec![]), (2024, 1, vec![january])]);
+
+        // Test case 4: No months
+        assert!(Recurrence::DayOfMonth(1)
+            .occurrences_by_month(&date(12, 1), 0)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_recurrence_prev_n() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: Weekly history in descending order
+        let recurrence = Recurrence::Weekly(Weekday::Mon);
+        assert_eq!(
+            recurrence.prev_n(&date(2023, 10, 16), 3),
+            [date(2023, 10, 9), date(2023, 10, 2), date(2023, 9, 25)]
+        );
+
+        // Test case 2: Day 31 skips the shorter months going backward
+        let recurrence = Recurrence::DayOfMonth(31);
+        assert_eq!(
+            recurrence.prev_n(&date(2023, 9, 15), 4),
+            [
+                date(2023, 8, 31),
+                date(2023, 7, 31),
+                date(2023, 5, 31),
+                date(2023, 3, 31),
+            ]
+        );
+
+        // Test case 3: The history steps back through exactly what next advances over
+        let recurrence = Recurrence::DayOfMonth(15);
+        let history = recurrence.prev_n(&date(2023, 6, 1), 5);
+        let mut forward: Vec<_> = recurrence
+            .occurrences(&date(2022, 12, 31))
+            .take(5)
+            .collect();
+        forward.reverse();
+        assert_eq!(history, forward);
+
+        // Test case 4: Asking for nothing, and running out at the start of the calendar
+        assert!(recurrence.prev_n(&date(2023, 6, 1), 0).is_empty());
+        let recurrence = Recurrence::Weekly(Weekday::Mon);
+        assert!(recurrence.prev_n(&NaiveDate::MIN, 3).is_empty());
+    }
+
+    #[test]
+    fn test_filter_recurrence() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+
+        // Test case 1: Filtering a range through a weekly recurrence
+        let dates = crate::dates_in_range(&date(10, 1), &date(10, 21));
+        let result: Vec<_> = filter_recurrence(dates, Recurrence::Weekly(Weekday::Wed)).collect();
+        assert_eq!(result, [date(10, 4), date(10, 11), date(10, 18)]);
+
+        // Test case 2: The input order is kept and non-matching dates are dropped
+        let dates = [date(12, 25), date(1, 25), date(3, 24), date(6, 25)];
+        let result: Vec<_> =
+            filter_recurrence(dates.into_iter(), Recurrence::DayOfMonth(25)).collect();
+        assert_eq!(result, [date(12, 25), date(1, 25), date(6, 25)]);
+
+        // Test case 3: The result matches the occurrences of the recurrence
+        let recurrence = Recurrence::DayOfMonth(31);
+        let dates = crate::dates_in_range(&date(1, 1), &date(12, 31));
+        let result: Vec<_> = filter_recurrence(dates, recurrence.clone()).collect();
+        let previous_year_end = NaiveDate::from_ymd_opt(2022, 12, 31).unwrap();
+        let expected: Vec<_> = recurrence.occurrences(&previous_year_end).take(7).collect();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_recurrence_except() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        let mondays_except_first = Recurrence::Except {
+            base: Box::new(Recurrence::Weekly(Weekday::Mon)),
+            exclude: Box::new(Recurrence::NthWeekdayOfMonth {
+                weekday: Weekday::Mon,
+                n: 1,
+            }),
+        };
+
+        // Test case 1: The first Monday of each month is skipped in both directions
+        let from = NaiveDate::from_ymd_opt(2023, 12, 25).unwrap();
+        assert_eq!(mondays_except_first.next(&from), Some(date(1, 8)));
+        assert_eq!(mondays_except_first.next(&date(1, 29)), Some(date(2, 12)));
+        assert_eq!(mondays_except_first.next(&date(3, 25)), Some(date(4, 8)));
+        assert_eq!(
+            mondays_except_first.prev_occurrence(&date(7, 8)),
+            Some(date(6, 24))
+        );
+        assert!(mondays_except_first.matches(&date(1, 8)));
+        assert!(!mondays_except_first.matches(&date(1, 1)));
+        assert!(!mondays_except_first.matches(&date(2, 5)));
+        assert!(!mondays_except_first.matches(&date(2, 6)));
+
+        // Test case 2: 2024 has 53 Mondays, 12 of which are the first of their month
+        assert_eq!(
+            mondays_except_first.count_occurrences(&date(1, 1), &date(12, 31)),
+            41
+        );
+        assert_eq!(mondays_except_first.occurrences_in_month(2024, 4).len(), 4);
+        assert_eq!(
+            mondays_except_first.step_between(&date(3, 25), &date(4, 8)),
+            Some(RecurrenceStep::Days(14))
+        );
+
+        // Test case 3: Excluding every other Monday leaves the Mondays in between
+        let off_weeks = Recurrence::Except {
+            base: Box::new(Recurrence::Weekly(Weekday::Mon)),
+            exclude: Box::new(Recurrence::EveryNWeeks {
+                weekday: Weekday::Mon,
+                n: 2,
+                anchor: date(1, 1),
+            }),
+        };
+        let dates: Vec<_> = off_weeks.occurrences(&from).take(3).collect();
+        assert_eq!(dates, [date(1, 8), date(1, 22), date(2, 5)]);
+
+        // Test case 4: Excluding every base occurrence gives up after a bounded search
+        let never = Recurrence::Except {
+            base: Box::new(Recurrence::Weekly(Weekday::Mon)),
+            exclude: Box::new(Recurrence::Weekly(Weekday::Mon)),
+        };
+        assert_eq!(never.next(&from), None);
+        assert_eq!(never.prev_occurrence(&from), None);
+
+        // Test case 5: Both recurrences are validated
+        let invalid = Recurrence::Except {
+            base: Box::new(Recurrence::Weekly(Weekday::Mon)),
+            exclude: Box::new(Recurrence::DayOfMonth(32)),
+        };
+        assert_eq!(invalid.validate(), Err(NextDayError::InvalidDay(32)));
+
+        // Test case 6: Exclusions chain on the base in the string form
+        assert_eq!(
+            mondays_except_first.to_string(),
+            "weekly:MON except nth:1:MON"
+        );
+        let chained: Recurrence = "weekly:MON except nth:1:MON except monthly:15"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            chained,
+            Recurrence::Except {
+                base: Box::new(mondays_except_first),
+                exclude: Box::new(Recurrence::DayOfMonth(15)),
+            }
+        );
+    }
+
+    #[test]
+    fn test_recurrence_nth_weekday_of_month() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        let first_monday = Recurrence::NthWeekdayOfMonth {
+            weekday: Weekday::Mon,
+            n: 1,
+        };
+        let fifth_monday = Recurrence::NthWeekdayOfMonth {
+            weekday: Weekday::Mon,
+            n: 5,
+        };
+
+        // Test case 1: The first Monday of each month, in both directions
+        assert_eq!(first_monday.next(&date(1, 1)), Some(date(2, 5)));
+        assert_eq!(first_monday.next(&date(1, 31)), Some(date(2, 5)));
+        assert_eq!(first_monday.prev_occurrence(&date(2, 5)), Some(date(1, 1)));
+        assert!(first_monday.matches(&date(2, 5)));
+        assert!(!first_monday.matches(&date(2, 12)));
+        assert_eq!(first_monday.occurrences_in_month(2024, 9), [date(9, 2)]);
+
+        // Test case 2: A 5th Monday skips the months that only have four
+        assert_eq!(fifth_monday.next(&date(2, 1)), Some(date(4, 29)));
+        assert_eq!(fifth_monday.prev_occurrence(&date(7, 1)), Some(date(4, 29)));
+        assert_eq!(
+            fifth_monday.step_between(&date(1, 29), &date(4, 29)),
+            Some(RecurrenceStep::Months(3))
+        );
+        assert_eq!(
+            fifth_monday.count_occurrences(&date(1, 1), &date(12, 31)),
+            5
+        );
+        assert!(fifth_monday.occurrences_in_month(2024, 2).is_empty());
+
+        // Test case 3: Every nth weekday agrees with a day-by-day scan over several years
+        let start = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+        let end = NaiveDate::from_ymd_opt(2030, 12, 31).unwrap();
+        for weekday in [Weekday::Mon, Weekday::Thu, Weekday::Sun] {
+            for n in 1..=5 {
+                let recurrence = Recurrence::NthWeekdayOfMonth { weekday, n };
+                let expected: Vec<_> = crate::dates_in_range(&start, &end)
+                    .filter(|date| *date > start)
+                    .filter(|date| {
+                        crate::find_nth_weekday_of_month(date.year(), date.month(), weekday, n)
+                            == Some(*date)
+                    })
+                    .collect();
+                let result: Vec<_> = recurrence
+                    .occurrences(&start)
+                    .take_while(|date| *date <= end)
+                    .collect();
+                assert_eq!(result, expected, "{recurrence}");
+                assert!(expected.iter().all(|date| recurrence.matches(date)));
+                let last = *expected.last().unwrap();
+                assert_eq!(
+                    recurrence.prev_n(&last, expected.len() - 1),
+                    expected[..expected.len() - 1]
+                        .iter()
+                        .rev()
+                        .copied()
+                        .collect::<Vec<_>>()
+                );
+            }
+        }
+
+        // Test case 4: Only the 1st through 5th occurrences are valid
+        assert_eq!(fifth_monday.validate(), Ok(()));
+        for n in [0, 6] {
+            let recurrence = Recurrence::NthWeekdayOfMonth {
+                weekday: Weekday::Mon,
+                n,
+            };
+            assert_eq!(
+                recurrence.validate(),
+                Err(NextDayError::InvalidOccurrence(n))
+            );
+            assert_eq!(recurrence.next(&date(1, 1)), None);
+        }
+    }
+}
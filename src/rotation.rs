@@ -0,0 +1,138 @@
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+use crate::days_forward_to;
+
+/// A schedule that cycles through an ordered list of weekdays, such as an on-call rotation.
+///
+/// The first occurrence is the first date on or after `anchor` that falls on `order[0]`. Each
+/// following occurrence is the next date after the previous one that falls on the next weekday
+/// in `order`, wrapping around to `order[0]` after the last entry. So `[Fri, Mon]` alternates
+/// between Fridays and Mondays, and `[Mon, Mon, Tue]` takes two consecutive Mondays before
+/// moving on to a Tuesday.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use next_matching_day::RotationSchedule;
+///
+/// let schedule = RotationSchedule {
+///     order: vec![Weekday::Wed, Weekday::Mon],
+///     anchor: NaiveDate::from_ymd_opt(2023, 10, 16).unwrap(), // A Monday
+/// };
+/// // Wednesday the 18th, then Monday the 23rd, then Wednesday the 25th, ...
+/// let from = NaiveDate::from_ymd_opt(2023, 10, 18).unwrap();
+/// assert_eq!(schedule.next(&from), NaiveDate::from_ymd_opt(2023, 10, 23));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RotationSchedule {
+    /// The weekdays of the rotation, in the order they take turns.
+    pub order: Vec<Weekday>,
+    /// The date the rotation starts from, which fixes its phase.
+    pub anchor: NaiveDate,
+}
+
+impl RotationSchedule {
+    /// Finds the next occurrence of the rotation strictly after `from`.
+    ///
+    /// If `from` is before the first occurrence, the first occurrence is returned.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<NaiveDate>` containing the next occurrence. Returns `None` if `order` is empty
+    /// or if the calculation overflows.
+    pub fn next(&self, from: &NaiveDate) -> Option<NaiveDate> {
+        let (&first_weekday, _) = self.order.split_first()?;
+        let first = self.anchor.checked_add_days(Days::new(
+            first_weekday.days_since(self.anchor.weekday()).into(),
+        ))?;
+        if *from < first {
+            return Some(first);
+        }
+
+        // Days from the start of a cycle to each slot, and the length of the whole cycle.
+        let mut offsets = Vec::with_capacity(self.order.len());
+        let mut period = 0;
+        for (index, &weekday) in self.order.iter().enumerate() {
+            offsets.push(period);
+            let following = self.order[(index + 1) % self.order.len()];
+            period += u64::from(days_forward_to(weekday, following));
+        }
+
+        let days = (*from - first).num_days() as u64;
+        let cycle_start = days / period * period;
+        let offset = offsets
+            .iter()
+            .map(|offset| cycle_start + offset)
+            .find(|&offset| offset > days)
+            .unwrap_or(cycle_start + period);
+        first.checked_add_days(Days::new(offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotation_schedule_next() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+
+        // Test case 1: The rotation advances one slot per occurrence
+        let schedule = RotationSchedule {
+            order: vec![Weekday::Mon, Weekday::Wed, Weekday::Fri],
+            anchor: date(10, 14), // Saturday
+        };
+        let mut current = date(10, 14);
+        let mut occurrences = Vec::new();
+        for _ in 0..7 {
+            current = schedule.next(&current).unwrap();
+            occurrences.push(current);
+        }
+        assert_eq!(
+            occurrences,
+            [
+                date(10, 16),
+                date(10, 18),
+                date(10, 20),
+                date(10, 23),
+                date(10, 25),
+                date(10, 27),
+                date(10, 30),
+            ]
+        );
+
+        // Test case 2: A custom order that is not in weekday order
+        let schedule = RotationSchedule {
+            order: vec![Weekday::Fri, Weekday::Mon, Weekday::Mon],
+            anchor: date(10, 16), // Monday, so the first occurrence is Friday the 20th
+        };
+        let mut current = date(10, 1);
+        let mut occurrences = Vec::new();
+        for _ in 0..5 {
+            current = schedule.next(&current).unwrap();
+            occurrences.push(current);
+        }
+        assert_eq!(
+            occurrences,
+            [
+                date(10, 20),
+                date(10, 23),
+                date(10, 30),
+                date(11, 3),
+                date(11, 6),
+            ]
+        );
+
+        // Test case 3: Starting between occurrences respects the phase
+        assert_eq!(schedule.next(&date(10, 24)), Some(date(10, 30)));
+        assert_eq!(schedule.next(&date(11, 1)), Some(date(11, 3)));
+
+        // Test case 4: An empty rotation never occurs
+        let schedule = RotationSchedule {
+            order: Vec::new(),
+            anchor: date(10, 16),
+        };
+        assert_eq!(schedule.next(&date(10, 16)), None);
+    }
+}
@@ -0,0 +1,157 @@
+use chrono::{Datelike, NaiveDate};
+
+/// The hemisphere a date is observed in, which decides the names of the seasons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Hemisphere {
+    /// Summer runs from June to August.
+    Northern,
+    /// Summer runs from December to February.
+    Southern,
+}
+
+/// A meteorological season.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Season {
+    /// March to May in the northern hemisphere, September to November in the southern.
+    Spring,
+    /// June to August in the northern hemisphere, December to February in the southern.
+    Summer,
+    /// September to November in the northern hemisphere, March to May in the southern.
+    Autumn,
+    /// December to February in the northern hemisphere, June to August in the southern.
+    Winter,
+}
+
+/// Returns the meteorological season a date falls in.
+///
+/// Meteorological seasons are whole groups of three months starting on March 1st, June 1st,
+/// September 1st and December 1st. In the northern hemisphere these start spring, summer,
+/// autumn and winter, and in the southern hemisphere autumn, winter, spring and summer.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::{season_of, Hemisphere, Season};
+///
+/// let date = NaiveDate::from_ymd_opt(2023, 7, 15).unwrap();
+/// assert_eq!(season_of(&date, Hemisphere::Northern), Season::Summer);
+/// assert_eq!(season_of(&date, Hemisphere::Southern), Season::Winter);
+/// ```
+pub fn season_of(date: &NaiveDate, hemisphere: Hemisphere) -> Season {
+    let northern = match date.month() {
+        3..=5 => Season::Spring,
+        6..=8 => Season::Summer,
+        9..=11 => Season::Autumn,
+        _ => Season::Winter,
+    };
+    match hemisphere {
+        Hemisphere::Northern => northern,
+        Hemisphere::Southern => match northern {
+            Season::Spring => Season::Autumn,
+            Season::Summer => Season::Winter,
+            Season::Autumn => Season::Spring,
+            Season::Winter => Season::Summer,
+        },
+    }
+}
+
+/// Finds the start of the next meteorological season.
+///
+/// The result is the next of March 1st, June 1st, September 1st and December 1st strictly after
+/// `current_date`. The seasons start on the same dates in both hemispheres, so the hemisphere
+/// does not change the result; use [`season_of`] on it to get the name of the season that
+/// starts.
+///
+/// # Panics
+///
+/// Panics if the next season start is after [`NaiveDate::MAX`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use next_matching_day::{find_next_season_start, season_of, Hemisphere, Season};
+///
+/// let current_date = NaiveDate::from_ymd_opt(2023, 10, 15).unwrap();
+/// let start = find_next_season_start(&current_date, Hemisphere::Southern);
+/// assert_eq!(start, NaiveDate::from_ymd_opt(2023, 12, 1).unwrap());
+/// assert_eq!(season_of(&start, Hemisphere::Southern), Season::Summer);
+/// ```
+pub fn find_next_season_start(current_date: &NaiveDate, _hemisphere: Hemisphere) -> NaiveDate {
+    let (year, month) = match current_date.month() {
+        1 | 2 => (current_date.year(), 3),
+        3..=5 => (current_date.year(), 6),
+        6..=8 => (current_date.year(), 9),
+        9..=11 => (current_date.year(), 12),
+        _ => (current_date.year() + 1, 3),
+    };
+    NaiveDate::from_ymd_opt(year, month, 1).expect("next season start out of range")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_season_of() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2023, m, d).unwrap();
+
+        // Test case 1: Northern hemisphere, on both sides of each boundary
+        for (month, day, season) in [
+            (2, 28, Season::Winter),
+            (3, 1, Season::Spring),
+            (5, 31, Season::Spring),
+            (6, 1, Season::Summer),
+            (8, 31, Season::Summer),
+            (9, 1, Season::Autumn),
+            (11, 30, Season::Autumn),
+            (12, 1, Season::Winter),
+        ] {
+            assert_eq!(season_of(&date(month, day), Hemisphere::Northern), season);
+        }
+
+        // Test case 2: Southern hemisphere labels are shifted by half a year
+        assert_eq!(
+            season_of(&date(1, 15), Hemisphere::Southern),
+            Season::Summer
+        );
+        assert_eq!(
+            season_of(&date(4, 15), Hemisphere::Southern),
+            Season::Autumn
+        );
+        assert_eq!(
+            season_of(&date(7, 15), Hemisphere::Southern),
+            Season::Winter
+        );
+        assert_eq!(
+            season_of(&date(10, 15), Hemisphere::Southern),
+            Season::Spring
+        );
+    }
+
+    #[test]
+    fn test_find_next_season_start() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Test case 1: On and around the boundaries
+        for hemisphere in [Hemisphere::Northern, Hemisphere::Southern] {
+            for (current, expected) in [
+                (date(2023, 2, 28), date(2023, 3, 1)),
+                (date(2023, 3, 1), date(2023, 6, 1)),
+                (date(2023, 5, 31), date(2023, 6, 1)),
+                (date(2023, 8, 31), date(2023, 9, 1)),
+                (date(2023, 9, 1), date(2023, 12, 1)),
+                (date(2023, 12, 1), date(2024, 3, 1)),
+                (date(2023, 12, 31), date(2024, 3, 1)),
+            ] {
+                assert_eq!(find_next_season_start(&current, hemisphere), expected);
+            }
+        }
+
+        // Test case 2: The season that starts depends on the hemisphere
+        let start = find_next_season_start(&date(2023, 5, 1), Hemisphere::Northern);
+        assert_eq!(season_of(&start, Hemisphere::Northern), Season::Summer);
+        assert_eq!(season_of(&start, Hemisphere::Southern), Season::Winter);
+    }
+}
@@ -0,0 +1,179 @@
+use chrono::NaiveDate;
+
+use crate::{find_next_annual_date, find_next_day_of_month, find_next_weekday};
+
+/// The Julian day number of 0001-01-01, which is day 1 counted from the common era.
+const JULIAN_DAY_OF_CE: i32 = 1_721_425;
+
+fn to_chrono_date(date: time::Date) -> Option<NaiveDate> {
+    NaiveDate::from_num_days_from_ce_opt(date.to_julian_day() - JULIAN_DAY_OF_CE)
+}
+
+fn from_chrono_date(date: NaiveDate) -> Option<time::Date> {
+    use chrono::Datelike;
+
+    time::Date::from_julian_day(date.num_days_from_ce() + JULIAN_DAY_OF_CE).ok()
+}
+
+fn to_chrono_weekday(weekday: time::Weekday) -> chrono::Weekday {
+    match weekday {
+        time::Weekday::Monday => chrono::Weekday::Mon,
+        time::Weekday::Tuesday => chrono::Weekday::Tue,
+        time::Weekday::Wednesday => chrono::Weekday::Wed,
+        time::Weekday::Thursday => chrono::Weekday::Thu,
+        time::Weekday::Friday => chrono::Weekday::Fri,
+        time::Weekday::Saturday => chrono::Weekday::Sat,
+        time::Weekday::Sunday => chrono::Weekday::Sun,
+    }
+}
+
+/// The `time` counterpart of [`find_next_weekday`].
+///
+/// The date is converted to `chrono` internally, so the semantics are exactly the same. Returns
+/// `None` if the result is outside the range supported by `time::Date`.
+///
+/// This function is only available with the `time` feature, which is disabled by default.
+///
+/// # Examples
+///
+/// ```
+/// use next_matching_day::find_next_weekday_time;
+/// use time::{Date, Month, Weekday};
+///
+/// let current_date = Date::from_calendar_date(2023, Month::October, 15).unwrap(); // A Sunday
+/// let next_monday = find_next_weekday_time(current_date, Weekday::Monday).unwrap();
+/// assert_eq!(
+///     next_monday,
+///     Date::from_calendar_date(2023, Month::October, 16).unwrap()
+/// );
+/// ```
+pub fn find_next_weekday_time(date: time::Date, weekday: time::Weekday) -> Option<time::Date> {
+    let date = to_chrono_date(date)?;
+    from_chrono_date(find_next_weekday(&date, to_chrono_weekday(weekday))?)
+}
+
+/// The `time` counterpart of [`find_next_day_of_month`].
+///
+/// Returns `None` in the same cases, or if the result is outside the range supported by
+/// `time::Date`.
+///
+/// This function is only available with the `time` feature, which is disabled by default.
+pub fn find_next_day_of_month_time(date: time::Date, next_day: u32) -> Option<time::Date> {
+    let date = to_chrono_date(date)?;
+    from_chrono_date(find_next_day_of_month(&date, next_day)?)
+}
+
+/// The `time` counterpart of [`find_next_annual_date`].
+///
+/// The month is given as a `time::Month` rather than a number. Returns `None` in the same cases,
+/// or if the result is outside the range supported by `time::Date`.
+///
+/// This function is only available with the `time` feature, which is disabled by default.
+pub fn find_next_annual_date_time(
+    date: time::Date,
+    next_month: time::Month,
+    next_day: u32,
+) -> Option<time::Date> {
+    let date = to_chrono_date(date)?;
+    from_chrono_date(find_next_annual_date(
+        &date,
+        u8::from(next_month).into(),
+        next_day,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Datelike;
+
+    fn chrono_date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn time_date(y: i32, m: u32, d: u32) -> time::Date {
+        let month = time::Month::try_from(m as u8).unwrap();
+        time::Date::from_calendar_date(y, month, d as u8).unwrap()
+    }
+
+    #[test]
+    fn test_date_conversion() {
+        // Test case 1: Round trip through both types
+        for (y, m, d) in [
+            (1, 1, 1),
+            (1970, 1, 1),
+            (2024, 2, 29),
+            (-100, 3, 1),
+            (9999, 12, 31),
+        ] {
+            assert_eq!(
+                to_chrono_date(time_date(y, m, d)),
+                Some(chrono_date(y, m, d))
+            );
+            assert_eq!(
+                from_chrono_date(chrono_date(y, m, d)),
+                Some(time_date(y, m, d))
+            );
+        }
+
+        // Test case 2: Dates outside the range of `time` do not convert
+        assert_eq!(from_chrono_date(chrono_date(10000, 1, 1)), None);
+    }
+
+    #[test]
+    fn test_find_next_weekday_time() {
+        let weekdays = [
+            time::Weekday::Monday,
+            time::Weekday::Tuesday,
+            time::Weekday::Wednesday,
+            time::Weekday::Thursday,
+            time::Weekday::Friday,
+            time::Weekday::Saturday,
+            time::Weekday::Sunday,
+        ];
+
+        let mut date = chrono_date(2023, 12, 20);
+        while date <= chrono_date(2024, 3, 10) {
+            let input = time_date(date.year(), date.month(), date.day());
+            for weekday in weekdays {
+                let expected = find_next_weekday(&date, to_chrono_weekday(weekday));
+                let result = find_next_weekday_time(input, weekday);
+                assert_eq!(result.and_then(to_chrono_date), expected);
+                assert_eq!(result.unwrap().weekday(), weekday);
+            }
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_find_next_day_of_month_time() {
+        // Test case 1: Agrees with the chrono function, including skipped short months
+        for (y, m, d, day) in [(2023, 10, 15, 20), (2023, 1, 31, 31), (2023, 2, 1, 29)] {
+            let expected = find_next_day_of_month(&chrono_date(y, m, d), day);
+            let result = find_next_day_of_month_time(time_date(y, m, d), day);
+            assert_eq!(result.and_then(to_chrono_date), expected);
+        }
+
+        // Test case 2: Invalid day
+        assert_eq!(find_next_day_of_month_time(time_date(2023, 1, 1), 32), None);
+    }
+
+    #[test]
+    fn test_find_next_annual_date_time() {
+        // Test case 1: Agrees with the chrono function, including February 29
+        for (y, m, d, month, day) in [
+            (2023, 5, 15, 6, 20),
+            (2023, 8, 1, 7, 1),
+            (2024, 3, 20, 2, 29),
+        ] {
+            let expected = find_next_annual_date(&chrono_date(y, m, d), month, day);
+            let month = time::Month::try_from(month as u8).unwrap();
+            let result = find_next_annual_date_time(time_date(y, m, d), month, day);
+            assert_eq!(result.and_then(to_chrono_date), expected);
+        }
+
+        // Test case 2: The result is out of the range of `time`
+        let result = find_next_annual_date_time(time_date(9999, 6, 1), time::Month::January, 1);
+        assert_eq!(result, None);
+    }
+}